@@ -0,0 +1,143 @@
+//! # Client Helpers Module
+//!
+//! This module contains off-chain helpers for frontends and SDKs that read
+//! staking pool state. Nothing here is used by the on-chain program, so the
+//! module is only compiled with the `client` feature enabled.
+//!
+//! ## Key Functions
+//!
+//! - `effective_apr`: Annualized reward rate of a staking pool.
+//! - `format_amount`: Human-readable rendering of a raw token amount.
+//! - `pool_account_size`: Bytes to allocate for a pool storage account.
+//! - `compute_unit_limit`: Default compute unit limit for a staking instruction.
+//! - `with_compute_unit_limit`: Prepends a compute unit limit to an instruction.
+
+use crate::instruction::Instruction as StakingInstruction;
+use crate::state::PoolStorageAccount;
+use solana_compute_budget_interface::ComputeBudgetInstruction;
+use solana_program::instruction::Instruction;
+
+/// Compute units requested for instructions that only touch the pool's fixed fields.
+///
+/// This is an unmeasured estimate, not a profiled figure. `Initialize` under
+/// `log_verbose` pretty-prints the whole pool, including the checkpoint
+/// array, and may need more.
+pub const DEFAULT_COMPUTE_UNITS: u32 = 20_000;
+
+/// Compute units requested for instructions that scan the checkpoint ring buffer.
+///
+/// Like `DEFAULT_COMPUTE_UNITS`, this is an unmeasured estimate.
+pub const CHECKPOINT_COMPUTE_UNITS: u32 = 40_000;
+
+/// Computes the effective annual percentage rate (APR) of a staking pool.
+///
+/// `rewards_per_token` is a plain integer count of reward base units that each
+/// staked base unit earns per second, with no fixed-point scaling. The
+/// smallest non-zero rate, `1`, therefore already means an APR of
+/// `seconds_per_year` (3,153,600,000% over a 365-day year). The APR compares
+/// raw base units, so it is only meaningful as a percentage when the reward
+/// and stake mints have the same decimals and value.
+///
+/// Every staked unit earns the same rate, so the APR does not depend on
+/// `total_staked`.
+///
+/// # Parameters
+/// - `pool`: The deserialized staking pool state.
+/// - `seconds_per_year`: Length of a year in seconds (e.g. `31_536_000`).
+///
+/// # Returns
+/// The APR as a fraction, where `1.0` means 100%.
+///
+/// An empty pool (`total_staked == 0`) emits nothing and returns `0.0`. A
+/// non-positive `seconds_per_year` also returns `0.0`.
+pub fn effective_apr(pool: &PoolStorageAccount, seconds_per_year: i64) -> f64 {
+    if pool.total_staked == 0 || seconds_per_year <= 0 {
+        return 0.0;
+    }
+
+    pool.rewards_per_token as f64 * seconds_per_year as f64
+}
+
+/// Renders a raw token amount as a human-readable decimal string.
+///
+/// The amount is split into whole and fractional parts using the mint's
+/// `decimals`, and trailing zeros in the fractional part are trimmed.
+///
+/// # Parameters
+/// - `amount`: The raw token amount in base units.
+/// - `decimals`: The number of decimals of the token mint.
+///
+/// # Example
+/// ```rust
+/// use stakingdapp::client::format_amount;
+///
+/// assert_eq!(format_amount(1_500_000, 6), "1.5");
+/// assert_eq!(format_amount(0, 9), "0");
+/// ```
+pub fn format_amount(amount: u64, decimals: u8) -> String {
+    let digits = amount.to_string();
+    let decimals = decimals as usize;
+
+    if decimals == 0 {
+        return digits;
+    }
+
+    // Left-pad so there is always at least one whole digit
+    let padded = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = padded.split_at(padded.len() - decimals);
+
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+/// Returns the number of bytes to allocate for a pool storage account.
+///
+/// Pass this as the `space` of `SystemInstruction::CreateAccount` when creating
+/// the storage account for `Initialize`. It is always `PoolStorageAccount::LEN`.
+pub fn pool_account_size() -> usize {
+    PoolStorageAccount::LEN
+}
+
+/// Returns the default compute unit limit to request for `instruction`.
+///
+/// `RecordCheckpoint` and `QueryCheckpoint` get `CHECKPOINT_COMPUTE_UNITS`.
+/// Every other instruction gets `DEFAULT_COMPUTE_UNITS`.
+///
+/// Both defaults are unmeasured estimates. Profile against an SBF build and
+/// pass a different limit to `with_compute_unit_limit` if a transaction runs
+/// out of compute.
+pub fn compute_unit_limit(instruction: &StakingInstruction) -> u32 {
+    match instruction {
+        StakingInstruction::RecordCheckpoint {} | StakingInstruction::QueryCheckpoint { .. } => {
+            CHECKPOINT_COMPUTE_UNITS
+        }
+        _ => DEFAULT_COMPUTE_UNITS,
+    }
+}
+
+/// Returns `instruction` preceded by a `SetComputeUnitLimit` of `units`.
+///
+/// Pass `compute_unit_limit` of the staking instruction for the default, or
+/// any other value to override it.
+///
+/// # Example
+/// ```rust
+/// use solana_program::{instruction::Instruction, pubkey::Pubkey};
+/// use stakingdapp::client::{compute_unit_limit, with_compute_unit_limit};
+/// use stakingdapp::instruction::Instruction as StakingInstruction;
+///
+/// let data = StakingInstruction::RecordCheckpoint {};
+/// let ix = Instruction::new_with_borsh(Pubkey::new_unique(), &data, vec![]);
+/// let instructions = with_compute_unit_limit(ix, compute_unit_limit(&data));
+/// assert_eq!(instructions.len(), 2);
+/// ```
+pub fn with_compute_unit_limit(instruction: Instruction, units: u32) -> Vec<Instruction> {
+    vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(units),
+        instruction,
+    ]
+}
@@ -11,16 +11,27 @@
 //!
 //! By leveraging `thiserror`, the module provides human-readable error messages
 //! that improve developer experience and program maintainability.
+//!
+//! ## Conversions
+//! - `StakingError` converts into `ProgramError::Custom`.
+//! - Borsh (de)serialization errors convert into `ProgramError::BorshIoError`.
+//! - Failed integer narrowing converts into `StakingError::ArithmeticOverflow`.
 
 use solana_program::program_error::ProgramError;
+use std::num::TryFromIntError;
 use thiserror::Error;
 
+/// Result type for staking pool functions that return a value.
+///
+/// Functions that return nothing use `ProgramResult` instead.
+pub type StakingResult<T> = Result<T, ProgramError>;
+
 /// Custom errors for the staking pool program.
 ///
 /// These errors represent specific conditions that can occur during the
 /// execution of staking pool instructions. Each variant is mapped to
 /// a unique error code for use with Solana's `ProgramError`.
-#[derive(Debug, Copy, Clone, Error)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
 pub enum StakingError {
     /// The provided instruction data is invalid or unrecognized.
     #[error("Invalid Instruction")]
@@ -36,7 +47,43 @@ pub enum StakingError {
 
     /// The account has already been initialized and cannot be initialized again.
     #[error("Account already initialized")]
-    AccountInitialized,
+    AlreadyInitialized,
+
+    /// An arithmetic operation overflowed or a value did not fit its target type.
+    #[error("Arithmetic overflow")]
+    ArithmeticOverflow,
+
+    /// The account passed as a program to invoke is not executable.
+    #[error("Invalid program")]
+    InvalidProgram,
+
+    /// The pool still has stakers or staked tokens and cannot be closed.
+    #[error("Pool has a non-zero balance")]
+    NonZeroBalance,
+
+    /// The same account was passed in more than one account slot.
+    #[error("Duplicate account")]
+    DuplicateAccount,
+
+    /// The signer is not the authority required by the instruction.
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    /// The requested reward rate exceeds `MAX_REWARD_RATE`.
+    #[error("Reward rate too high")]
+    RateTooHigh,
+
+    /// The account data has the wrong length or does not deserialize.
+    #[error("Invalid account data")]
+    InvalidAccountData,
+
+    /// No retained checkpoint was taken at or before the requested slot.
+    #[error("No checkpoint")]
+    NoCheckpoint,
+
+    /// The pool has been shut down by `EmergencyShutdown`.
+    #[error("Pool shut down")]
+    PoolShutdown,
 }
 
 /// Converts `StakingError` into Solana's `ProgramError`.
@@ -57,3 +104,13 @@ impl From<StakingError> for ProgramError {
         ProgramError::Custom(err as u32)
     }
 }
+
+/// Converts a failed integer narrowing into `StakingError::ArithmeticOverflow`.
+///
+/// This lets `u64::try_from(value)?` be used directly in functions returning
+/// `StakingError`, and through it `ProgramError`.
+impl From<TryFromIntError> for StakingError {
+    fn from(_: TryFromIntError) -> Self {
+        StakingError::ArithmeticOverflow
+    }
+}
@@ -1,4 +1,6 @@
+use crate::state::Role;
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
 /// The `Instruction` enum defines the set of instructions that can be issued to the program.
 ///
@@ -12,6 +14,16 @@ use borsh::{BorshDeserialize, BorshSerialize};
 /// - `Stake`: Stakes a specified amount of tokens.
 /// - `Unstake`: Unstakes a specified amount of tokens.
 /// - `Claim`: Claims rewards for the user.
+/// - `RecordCheckpoint`: Records a `total_staked` checkpoint for the current slot.
+/// - `LogConfig`: Logs the constants compiled into the program.
+/// - `ClosePool`: Closes a wound-down pool and reclaims its rent.
+/// - `ProposeAuthority`: Proposes a new pool authority.
+/// - `AcceptAuthority`: Accepts a proposed pool authority handoff.
+/// - `CancelAuthority`: Cancels a pending pool authority handoff.
+/// - `SetRole`: Assigns the `Operator` or `Emergency` role to a key.
+/// - `QueryCheckpoint`: Logs the pool's `total_staked` as of a past slot.
+/// - `EmergencyShutdown`: Permanently shuts the pool down.
+/// - `RefundUninitialized`: Reclaims the lamports of a storage account that was never initialized.
 ///
 /// # Serialization
 ///
@@ -67,4 +79,127 @@ pub enum Instruction {
     ///
     /// This instruction allows the user to claim accumulated rewards based on their staking activity.
     Claim {},
+
+    /// Records the pool's current `total_staked` as a checkpoint for the current slot.
+    ///
+    /// Checkpoints are kept in a fixed-size ring buffer on the pool, so only the
+    /// most recent `MAX_CHECKPOINTS` are retained.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer]` Pool operator.
+    /// 1. `[writable]` Pool storage account.
+    RecordCheckpoint {},
+
+    /// Logs the constants compiled into the program.
+    ///
+    /// The constants are emitted as a single `Config:` log line of
+    /// space-separated `key=value` pairs, so clients can discover them instead
+    /// of hardcoding values that may change across deployments. The same
+    /// constants are set as a Borsh-encoded `ProgramConfig` in the return data.
+    ///
+    /// # Accounts
+    ///
+    /// None.
+    LogConfig {},
+
+    /// Closes a fully wound-down staking pool.
+    ///
+    /// The pool must have no users and no staked tokens. Its data is zeroed and
+    /// its lamports are transferred to the pool authority.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer, writable]` Pool authority, receives the reclaimed lamports.
+    /// 1. `[writable]` Pool storage account.
+    ClosePool {},
+
+    /// Proposes a new pool authority.
+    ///
+    /// The handoff only completes once the proposed authority signs
+    /// `AcceptAuthority`. Proposing again replaces any pending proposal.
+    ///
+    /// # Fields
+    ///
+    /// - `new`: The public key of the proposed authority.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer]` Current pool authority.
+    /// 1. `[writable]` Pool storage account.
+    ProposeAuthority { new: Pubkey },
+
+    /// Accepts a pending pool authority handoff, making the signer the pool authority.
+    ///
+    /// The `Operator` and `Emergency` roles move to the signer too if the
+    /// outgoing authority still holds them.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer]` Proposed pool authority.
+    /// 1. `[writable]` Pool storage account.
+    AcceptAuthority {},
+
+    /// Cancels a pending pool authority handoff.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer]` Current pool authority.
+    /// 1. `[writable]` Pool storage account.
+    CancelAuthority {},
+
+    /// Assigns a role on the pool to a new holder.
+    ///
+    /// The `Admin` role can't be assigned this way; it changes hands through
+    /// `ProposeAuthority` and `AcceptAuthority`.
+    ///
+    /// # Fields
+    ///
+    /// - `role`: The role to assign, `Operator` or `Emergency`.
+    /// - `holder`: The public key of the new role holder.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer]` Pool authority.
+    /// 1. `[writable]` Pool storage account.
+    SetRole { role: Role, holder: Pubkey },
+
+    /// Logs the pool's `total_staked` as of a past slot.
+    ///
+    /// The latest retained checkpoint taken at or before `slot` is logged as
+    /// `Checkpoint at slot <slot>: total_staked <total_staked>` and set as a
+    /// Borsh-encoded `Checkpoint` in the return data.
+    ///
+    /// # Fields
+    ///
+    /// - `slot`: The slot to look up.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[]` Pool storage account.
+    QueryCheckpoint { slot: u64 },
+
+    /// Permanently shuts the pool down.
+    ///
+    /// This is a one-way switch. Afterwards every instruction that changes the
+    /// pool fails with `StakingError::PoolShutdown`, except `ClosePool`, so
+    /// the rent can still be reclaimed once the pool is empty.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer]` Pool emergency role holder.
+    /// 1. `[writable]` Pool storage account.
+    EmergencyShutdown {},
+
+    /// Reclaims the lamports of a program-owned storage account that was never
+    /// initialized, for example one created with the wrong size.
+    ///
+    /// The storage account must sign; its keypair is the one that created the
+    /// account. Its data is zeroed and its lamports go to the recipient.
+    ///
+    /// # Accounts
+    ///
+    /// 0. `[signer, writable]` Uninitialized storage account.
+    /// 1. `[writable]` Recipient of the reclaimed lamports.
+    RefundUninitialized {},
 }
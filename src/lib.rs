@@ -1,7 +1,12 @@
+#[cfg(feature = "client")]
+pub mod client;
 pub mod error;
 pub mod instruction;
+pub mod logging;
+pub mod math;
 pub mod processor;
 pub mod state;
+pub mod validation;
 
 #[cfg(not(feature = "no-entrypoint"))]
 pub mod entrypoint;
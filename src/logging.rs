@@ -0,0 +1,41 @@
+//! # Logging Module
+//!
+//! This module controls how verbose the program's logs are. Pretty-printed
+//! debug dumps of account state are expensive in compute units, so they are
+//! only emitted when verbose logging is enabled at compile time.
+//!
+//! ## Feature Flags
+//! - `log_verbose`: Always emit debug dumps.
+//! - `log_quiet`: Never emit debug dumps.
+//! - Neither: Emit debug dumps in debug builds only.
+//!
+//! If both features are enabled, `log_quiet` wins.
+
+/// Whether debug dumps are emitted by `debug_msg!`.
+pub const VERBOSE: bool = verbosity(
+    cfg!(feature = "log_quiet"),
+    cfg!(feature = "log_verbose"),
+    cfg!(debug_assertions),
+);
+
+/// Resolves whether debug dumps are emitted from the build configuration.
+///
+/// `log_quiet` takes precedence over `log_verbose`, which takes precedence
+/// over the build profile.
+pub const fn verbosity(log_quiet: bool, log_verbose: bool, debug_assertions: bool) -> bool {
+    !log_quiet && (log_verbose || debug_assertions)
+}
+
+/// Logs a message with `msg!` only when `VERBOSE` is enabled.
+///
+/// Use this instead of `msg!` for debug dumps that are not needed in
+/// production, such as `{:#?}` pretty-prints of account state.
+macro_rules! debug_msg {
+    ($($arg:tt)*) => {
+        if $crate::logging::VERBOSE {
+            solana_program::msg!($($arg)*);
+        }
+    };
+}
+
+pub(crate) use debug_msg;
@@ -0,0 +1,41 @@
+//! # Staking Math Module
+//!
+//! This module contains checked arithmetic helpers shared by the staking
+//! pool instructions. Every helper reports failure as a `StakingError`
+//! instead of wrapping or truncating silently.
+//!
+//! ## Key Functions
+//!
+//! - `u128_to_u64_checked`: Narrows a `u128` intermediate value to a `u64` payout.
+//! - `apply_bps`: Scales an amount by a basis-point rate.
+
+use crate::error::StakingError;
+
+/// Number of basis points in 100%.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Converts a `u128` value into a `u64`, failing if it does not fit.
+///
+/// Reward math is carried out in `u128` to avoid intermediate overflow, but
+/// token amounts are `u64`. This helper must be used at every boundary where
+/// such a value becomes a payout, so it is never truncated with `as u64`.
+///
+/// # Errors
+/// - Returns `StakingError::ArithmeticOverflow` if `v` exceeds `u64::MAX`.
+pub fn u128_to_u64_checked(v: u128) -> Result<u64, StakingError> {
+    Ok(u64::try_from(v)?)
+}
+
+/// Computes `amount * bps / 10_000`, rounding down.
+///
+/// The multiplication is carried out in `u128`, so it cannot overflow for any
+/// `u64` amount. This helper should be used for all basis-point math, such as
+/// fees, penalties and boosts, instead of inline arithmetic on `u64`.
+///
+/// # Errors
+/// - Returns `StakingError::ArithmeticOverflow` if the result exceeds `u64::MAX`,
+///   which can only happen when `bps` is above `BPS_DENOMINATOR`.
+pub fn apply_bps(amount: u64, bps: u16) -> Result<u64, StakingError> {
+    let scaled = amount as u128 * bps as u128 / BPS_DENOMINATOR as u128;
+    u128_to_u64_checked(scaled)
+}
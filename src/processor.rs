@@ -8,16 +8,42 @@
 //! ## Instructions Supported
 //!
 //! - **Initialize**: Initializes the staking pool with a given reward rate per token.
+//! - **RecordCheckpoint**: Records a `total_staked` checkpoint for the current slot.
+//! - **LogConfig**: Logs the constants compiled into the program.
+//! - **ClosePool**: Closes a wound-down staking pool and reclaims its rent.
+//! - **ProposeAuthority** / **AcceptAuthority** / **CancelAuthority**: Two-step
+//!   handoff of the pool authority.
+//! - **SetRole**: Assigns the `Operator` or `Emergency` role.
+//! - **QueryCheckpoint**: Logs the pool's `total_staked` as of a past slot.
+//! - **EmergencyShutdown**: Permanently shuts the pool down.
+//! - **RefundUninitialized**: Reclaims the rent of a storage account that was never initialized.
 //!
 //! ## Key Functions
 //!
 //! - `process`: Entry point for processing instructions in the program.
 //! - `process_initialize_pool`: Handles the `Initialize` instruction, setting up the staking pool's state.
+//! - `process_record_checkpoint`: Handles the `RecordCheckpoint` instruction.
+//! - `process_log_config`: Handles the `LogConfig` instruction.
+//! - `process_close_pool`: Handles the `ClosePool` instruction.
+//! - `process_set_pending_authority`: Handles the `ProposeAuthority` and `CancelAuthority` instructions.
+//! - `process_accept_authority`: Handles the `AcceptAuthority` instruction.
+//! - `process_set_role`: Handles the `SetRole` instruction.
+//! - `process_query_checkpoint`: Handles the `QueryCheckpoint` instruction.
+//! - `process_emergency_shutdown`: Handles the `EmergencyShutdown` instruction.
+//! - `process_refund_uninitialized`: Handles the `RefundUninitialized` instruction.
 
 use crate::error::StakingError;
 use crate::instruction::Instruction;
-use borsh::BorshDeserialize;
-use solana_program::{account_info::*, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+use crate::logging::debug_msg;
+use crate::state::{AccountType, PoolStorageAccount, ProgramConfig, Role, MAX_REWARD_RATE};
+use crate::validation::{
+    load_account, require_active, require_distinct, require_owner, require_role, require_signer,
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::*, clock::Clock, entrypoint::ProgramResult, msg, program::set_return_data,
+    pubkey::Pubkey, sysvar::Sysvar,
+};
 
 /// Entry point for processing instructions in the staking pool program.
 ///
@@ -30,15 +56,23 @@ use solana_program::{account_info::*, entrypoint::ProgramResult, msg, pubkey::Pu
 /// - `instruction_data`: Serialized data for the instruction to be processed.
 ///
 /// # Errors
-/// - Returns `StakingError::InvalidInstruction` if the instruction is unrecognized.
+/// - Returns `StakingError::InvalidInstruction` if the instruction is unrecognized,
+///   malformed, or followed by trailing bytes.
 ///
 pub fn process(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    // Deserialize the instruction from the provided data
-    let instruction = Instruction::try_from_slice(instruction_data)?;
+    // Deserialize the instruction from the provided data. Trailing bytes are
+    // rejected explicitly rather than relying on the Borsh version's strictness
+    let mut data = instruction_data;
+    let instruction =
+        Instruction::deserialize(&mut data).map_err(|_| StakingError::InvalidInstruction)?;
+    if !data.is_empty() {
+        msg!("Instruction data has {} trailing bytes", data.len());
+        return Err(StakingError::InvalidInstruction.into());
+    }
 
     // Match the instruction type and call the appropriate handler
     match instruction {
@@ -46,6 +80,46 @@ pub fn process(
             msg!("Initialize pool");
             process_initialize_pool(program_id, accounts, rewards_per_token)
         }
+        Instruction::RecordCheckpoint {} => {
+            msg!("Record checkpoint");
+            process_record_checkpoint(program_id, accounts)
+        }
+        Instruction::LogConfig {} => {
+            msg!("Log config");
+            process_log_config()
+        }
+        Instruction::ClosePool {} => {
+            msg!("Close pool");
+            process_close_pool(program_id, accounts)
+        }
+        Instruction::ProposeAuthority { new } => {
+            msg!("Propose authority");
+            process_set_pending_authority(program_id, accounts, new)
+        }
+        Instruction::AcceptAuthority {} => {
+            msg!("Accept authority");
+            process_accept_authority(program_id, accounts)
+        }
+        Instruction::CancelAuthority {} => {
+            msg!("Cancel authority");
+            process_set_pending_authority(program_id, accounts, Pubkey::default())
+        }
+        Instruction::SetRole { role, holder } => {
+            msg!("Set role");
+            process_set_role(program_id, accounts, role, holder)
+        }
+        Instruction::QueryCheckpoint { slot } => {
+            msg!("Query checkpoint");
+            process_query_checkpoint(program_id, accounts, slot)
+        }
+        Instruction::EmergencyShutdown {} => {
+            msg!("Emergency shutdown");
+            process_emergency_shutdown(program_id, accounts)
+        }
+        Instruction::RefundUninitialized {} => {
+            msg!("Refund uninitialized");
+            process_refund_uninitialized(program_id, accounts)
+        }
         _ => Err(StakingError::InvalidInstruction.into()),
     }
 }
@@ -68,7 +142,10 @@ pub fn process(
 /// # Errors
 /// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
 /// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
 /// - Returns `StakingError::AlreadyInitialized` if the staking pool has already been initialized.
+/// - Returns `StakingError::RateTooHigh` if `rewards_per_token` exceeds `MAX_REWARD_RATE`.
 ///
 fn process_initialize_pool(
     program_id: &Pubkey,
@@ -80,34 +157,575 @@ fn process_initialize_pool(
 
     // Verify that the first account is a valid signer
     let signer = next_account_info(accounts_iter)?;
-    if !signer.is_signer {
-        return Err(StakingError::InvalidSigner.into());
-    }
+    require_signer(signer)?;
 
-    // Verify that the second account is the storage account and is owned by the program
+    // The second account is the storage account
     let storage = next_account_info(accounts_iter)?;
-    if storage.owner != program_id {
-        return Err(StakingError::InvalidOwner.into());
-    }
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[signer.key, storage.key])?;
 
     // Deserialize the storage account data into a PoolStorageAccount
-    let mut storage_data = PoolStorageAccount::try_from_slice(&storage.data.borrow())?;
-    if storage_data.is_initialized() {
-        return Err(StakingError::AlreadyInitialized.into());
+    let mut storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Uninitialized,
+        PoolStorageAccount::LEN,
+    )?;
+
+    // Reject reward rates that could drain the pool or overflow accrual
+    if rewards_per_token > MAX_REWARD_RATE {
+        msg!(
+            "Reward rate {} exceeds the maximum {}",
+            rewards_per_token,
+            MAX_REWARD_RATE
+        );
+        return Err(StakingError::RateTooHigh.into());
     }
 
     // Initialize the staking pool state
     storage_data.pool_authority = *signer.key;
+    storage_data.operator = *signer.key;
+    storage_data.emergency = *signer.key;
     storage_data.total_staked = 0u64;
     storage_data.user_count = 0u64;
     storage_data.rewards_per_token = rewards_per_token;
-    storage_data.is_initialized = true;
+    storage_data.account_type = AccountType::Pool;
 
     // Serialize the updated state back into the storage account
     storage_data.serialize(&mut &mut storage.data.borrow_mut()[..])?;
 
     // Log the initialization details for debugging
-    msg!("Staking pool is initialized {:#?}", storage_data);
+    debug_msg!("Staking pool is initialized {:#?}", storage_data);
+
+    Ok(())
+}
+
+/// Processes the `RecordCheckpoint` instruction.
+///
+/// This function snapshots the pool's current `total_staked` at the current
+/// slot into the pool's checkpoint ring buffer.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+///
+/// # Account Requirements
+/// - The first account must be the pool operator and must sign the transaction.
+/// - The second account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
+/// - Returns `StakingError::Unauthorized` if the first account is not the pool operator.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::PoolShutdown` if the pool has been shut down.
+///
+fn process_record_checkpoint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Verify that the first account is a valid signer
+    let operator = next_account_info(accounts_iter)?;
+    require_signer(operator)?;
+
+    // The second account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[operator.key, storage.key])?;
+
+    let mut storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+    require_role(&storage_data, operator, Role::Operator)?;
+    require_active(&storage_data)?;
+
+    let slot = Clock::get()?.slot;
+    storage_data.push_checkpoint(slot)?;
+
+    storage_data.serialize(&mut &mut storage.data.borrow_mut()[..])?;
+
+    msg!(
+        "Checkpoint recorded at slot {}: total_staked {}",
+        slot,
+        storage_data.total_staked
+    );
+
+    Ok(())
+}
+
+/// Processes the `LogConfig` instruction.
+///
+/// This function logs the program's compiled-in constants as one line of
+/// `key=value` pairs prefixed with `Config:`, and sets the same constants as
+/// a Borsh-encoded `ProgramConfig` in the transaction's return data.
+///
+/// # Account Requirements
+/// - No accounts are required.
+///
+fn process_log_config() -> ProgramResult {
+    let config = ProgramConfig::current();
+    msg!(
+        "Config: pool_account_len={} max_checkpoints={} bps_denominator={} max_reward_rate={}",
+        config.pool_account_len,
+        config.max_checkpoints,
+        config.bps_denominator,
+        config.max_reward_rate
+    );
+    set_return_data(&borsh::to_vec(&config)?);
+
+    Ok(())
+}
+
+/// Processes the `ClosePool` instruction.
+///
+/// This function closes a staking pool that has been fully wound down. The
+/// storage account data is zeroed so it cannot be reused, and all of its
+/// lamports are transferred to the pool authority.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+///
+/// # Account Requirements
+/// - The first account must be the pool authority, must sign the transaction
+///   and must be writable.
+/// - The second account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
+/// - Returns `StakingError::Unauthorized` if the first account is not the pool authority.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::NonZeroBalance` if the pool still has users or staked tokens.
+///
+fn process_close_pool(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Verify that the first account is a valid signer
+    let authority = next_account_info(accounts_iter)?;
+    require_signer(authority)?;
+
+    // The second account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[authority.key, storage.key])?;
+
+    let storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+    require_role(&storage_data, authority, Role::Admin)?;
+
+    // Only a pool without stakers can be closed
+    if storage_data.total_staked != 0 || storage_data.user_count != 0 {
+        return Err(StakingError::NonZeroBalance.into());
+    }
+
+    // Zero the data so the account can't be revived as an initialized pool
+    storage.data.borrow_mut().fill(0);
+
+    // Move all lamports to the authority; the runtime purges the empty account
+    let reclaimed = storage.lamports();
+    **authority.lamports.borrow_mut() = authority
+        .lamports()
+        .checked_add(reclaimed)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+    **storage.lamports.borrow_mut() = 0;
+
+    msg!("Staking pool closed, reclaimed {} lamports", reclaimed);
+
+    Ok(())
+}
+
+/// Processes the `ProposeAuthority` and `CancelAuthority` instructions.
+///
+/// This function stores `pending_authority` as the proposed new authority of
+/// the pool. `CancelAuthority` passes `Pubkey::default()`, clearing any
+/// pending proposal. The pool authority itself is unchanged until the
+/// proposed key signs `AcceptAuthority`.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+/// - `pending_authority`: The proposed authority, or `Pubkey::default()` to cancel.
+///
+/// # Account Requirements
+/// - The first account must be the current pool authority and must sign the transaction.
+/// - The second account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
+/// - Returns `StakingError::Unauthorized` if the first account is not the pool authority.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::PoolShutdown` if the pool has been shut down.
+///
+fn process_set_pending_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    pending_authority: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Verify that the first account is a valid signer
+    let authority = next_account_info(accounts_iter)?;
+    require_signer(authority)?;
+
+    // The second account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[authority.key, storage.key])?;
+
+    let mut storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+    require_role(&storage_data, authority, Role::Admin)?;
+    require_active(&storage_data)?;
+
+    storage_data.pending_authority = pending_authority;
+
+    storage_data.serialize(&mut &mut storage.data.borrow_mut()[..])?;
+
+    msg!("Pending authority set to {}", pending_authority);
+
+    Ok(())
+}
+
+/// Processes the `AcceptAuthority` instruction.
+///
+/// This function completes a pending authority handoff. The signer must be
+/// the `pending_authority` stored on the pool; it becomes the new pool
+/// authority and the pending proposal is cleared. The `Operator` and
+/// `Emergency` roles move to the new authority if the outgoing authority
+/// still holds them; roles delegated to other keys are left in place.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+///
+/// # Account Requirements
+/// - The first account must be the pending authority and must sign the transaction.
+/// - The second account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
+/// - Returns `StakingError::Unauthorized` if the first account is not the pending authority,
+///   including when no handoff is pending.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::PoolShutdown` if the pool has been shut down.
+///
+fn process_accept_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Verify that the first account is a valid signer
+    let new_authority = next_account_info(accounts_iter)?;
+    require_signer(new_authority)?;
+
+    // The second account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[new_authority.key, storage.key])?;
+
+    let mut storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+    require_active(&storage_data)?;
+
+    // `Pubkey::default()` can never sign, so this also rejects when nothing is pending
+    if storage_data.pending_authority != *new_authority.key {
+        msg!(
+            "Account {} is not the pending authority {}",
+            new_authority.key,
+            storage_data.pending_authority
+        );
+        return Err(StakingError::Unauthorized.into());
+    }
+
+    // Roles the outgoing authority still holds go with the handoff, so the old
+    // key keeps no control over the pool
+    let old_authority = storage_data.pool_authority;
+    if storage_data.operator == old_authority {
+        storage_data.operator = *new_authority.key;
+    }
+    if storage_data.emergency == old_authority {
+        storage_data.emergency = *new_authority.key;
+    }
+
+    storage_data.pool_authority = *new_authority.key;
+    storage_data.pending_authority = Pubkey::default();
+
+    storage_data.serialize(&mut &mut storage.data.borrow_mut()[..])?;
+
+    msg!("Pool authority transferred to {}", new_authority.key);
+
+    Ok(())
+}
+
+/// Processes the `SetRole` instruction.
+///
+/// This function assigns `role` to `holder`, replacing its previous holder.
+/// The `Admin` role is rejected; it only changes hands through the two-step
+/// authority handoff.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+/// - `role`: The role to assign.
+/// - `holder`: The new holder of the role.
+///
+/// # Account Requirements
+/// - The first account must be the pool authority and must sign the transaction.
+/// - The second account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
+/// - Returns `StakingError::Unauthorized` if the first account is not the pool authority.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::InvalidInstruction` if `role` is `Role::Admin`.
+/// - Returns `StakingError::PoolShutdown` if the pool has been shut down.
+///
+fn process_set_role(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    role: Role,
+    holder: Pubkey,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Verify that the first account is a valid signer
+    let authority = next_account_info(accounts_iter)?;
+    require_signer(authority)?;
+
+    // The second account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[authority.key, storage.key])?;
+
+    let mut storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+    require_role(&storage_data, authority, Role::Admin)?;
+    require_active(&storage_data)?;
+
+    match role {
+        Role::Operator => storage_data.operator = holder,
+        Role::Emergency => storage_data.emergency = holder,
+        Role::Admin => {
+            msg!("The Admin role is transferred with ProposeAuthority");
+            return Err(StakingError::InvalidInstruction.into());
+        }
+    }
+
+    storage_data.serialize(&mut &mut storage.data.borrow_mut()[..])?;
+
+    msg!("{:?} role set to {}", role, holder);
+
+    Ok(())
+}
+
+/// Processes the `QueryCheckpoint` instruction.
+///
+/// This function logs the latest retained checkpoint taken at or before
+/// `slot`, giving the pool's `total_staked` as of that slot, and sets it as
+/// a Borsh-encoded `Checkpoint` in the transaction's return data.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+/// - `slot`: The slot to look up.
+///
+/// # Account Requirements
+/// - The first account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::NoCheckpoint` if `slot` predates every retained checkpoint.
+///
+fn process_query_checkpoint(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    slot: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // The first account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    let storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+
+    let Some(checkpoint) = storage_data.checkpoint_at(slot) else {
+        msg!("No checkpoint at or before slot {}", slot);
+        return Err(StakingError::NoCheckpoint.into());
+    };
+
+    msg!(
+        "Checkpoint at slot {}: total_staked {}",
+        checkpoint.slot,
+        checkpoint.total_staked
+    );
+    set_return_data(&borsh::to_vec(&checkpoint)?);
+
+    Ok(())
+}
+
+/// Processes the `EmergencyShutdown` instruction.
+///
+/// This function permanently marks the pool as shut down. From then on
+/// `require_active` rejects every instruction that changes the pool, except
+/// `ClosePool`.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+///
+/// # Account Requirements
+/// - The first account must hold the `Emergency` role and must sign the transaction.
+/// - The second account must be the initialized storage account for the staking
+///   pool and must belong to the executing program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the first account is not a signer.
+/// - Returns `StakingError::Unauthorized` if the first account does not hold the `Emergency` role.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data is malformed.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `ProgramError::UninitializedAccount` if the staking pool is not initialized.
+/// - Returns `StakingError::PoolShutdown` if the pool has already been shut down.
+///
+fn process_emergency_shutdown(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Verify that the first account is a valid signer
+    let emergency = next_account_info(accounts_iter)?;
+    require_signer(emergency)?;
+
+    // The second account is the storage account
+    let storage = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[emergency.key, storage.key])?;
+
+    let mut storage_data: PoolStorageAccount = load_account(
+        storage,
+        program_id,
+        AccountType::Pool,
+        PoolStorageAccount::LEN,
+    )?;
+    require_role(&storage_data, emergency, Role::Emergency)?;
+    require_active(&storage_data)?;
+
+    storage_data.is_shutdown = true;
+
+    storage_data.serialize(&mut &mut storage.data.borrow_mut()[..])?;
+
+    msg!("Staking pool shut down by {}", emergency.key);
+
+    Ok(())
+}
+
+/// Processes the `RefundUninitialized` instruction.
+///
+/// This function returns the rent of a storage account that was funded but
+/// never initialized, such as one allocated with the wrong size. The account
+/// data is not required to be `PoolStorageAccount::LEN` bytes; it only has to
+/// be empty or start with the `AccountType::Uninitialized` discriminator.
+///
+/// # Parameters
+/// - `program_id`: The public key of the currently executing program.
+/// - `accounts`: The list of account information provided to the program.
+///
+/// # Account Requirements
+/// - The first account must be the storage account, must sign the transaction
+///   and must belong to the executing program.
+/// - The second account receives the lamports and must be writable.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the storage account is not a signer.
+/// - Returns `StakingError::InvalidOwner` if the storage account is not owned by the program.
+/// - Returns `StakingError::DuplicateAccount` if the same account is passed twice.
+/// - Returns `StakingError::AlreadyInitialized` if the storage account holds an initialized account.
+/// - Returns `StakingError::InvalidAccountData` if the storage account data starts with an unknown type.
+///
+fn process_refund_uninitialized(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // The storage account signs with the keypair that created it
+    let storage = next_account_info(accounts_iter)?;
+    require_signer(storage)?;
+    require_owner(storage, program_id)?;
+
+    // The second account receives the lamports
+    let recipient = next_account_info(accounts_iter)?;
+
+    // Reject the same account being passed in more than one slot
+    require_distinct(&[storage.key, recipient.key])?;
+
+    // An account of the wrong size can't be loaded, so only check its type
+    let data = storage.data.borrow();
+    if !data.is_empty() && AccountType::read(&data)? != AccountType::Uninitialized {
+        return Err(StakingError::AlreadyInitialized.into());
+    }
+    drop(data);
+
+    // Zero any partially written data before the account is purged
+    storage.data.borrow_mut().fill(0);
+
+    // Move all lamports to the recipient; the runtime purges the empty account
+    let refunded = storage.lamports();
+    **recipient.lamports.borrow_mut() = recipient
+        .lamports()
+        .checked_add(refunded)
+        .ok_or(StakingError::ArithmeticOverflow)?;
+    **storage.lamports.borrow_mut() = 0;
+
+    msg!(
+        "Uninitialized storage account refunded {} lamports",
+        refunded
+    );
 
     Ok(())
 }
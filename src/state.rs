@@ -10,17 +10,161 @@
 //! the number of users participating in the staking pool, and the reward distribution logic.
 //!
 //! ## Fields
+//! - `account_type`: Discriminator identifying the account as a pool.
 //! - `pool_authority`: Public key of the staking pool's owner or manager.
 //! - `total_staked`: Total amount of tokens staked in the pool.
 //! - `user_count`: Number of users currently participating in the staking pool.
 //! - `rewards_per_token`: Amount of rewards allocated per token staked.
+//! - `checkpoints`: Ring buffer of recent `(slot, total_staked)` snapshots.
+//! - `checkpoint_count`: Number of checkpoints ever recorded.
+//! - `pending_authority`: Proposed new pool authority awaiting acceptance.
+//! - `operator`: Holder of the `Operator` role.
+//! - `emergency`: Holder of the `Emergency` role.
+//! - `is_shutdown`: Whether the pool has been shut down by `EmergencyShutdown`.
 //!
 //! ## Usage
 //! This struct is serialized and deserialized using the `borsh` library for efficient
 //! storage in Solana accounts. It serves as the main state container for the staking pool.
+//! Clients that only need the static configuration can decode `config_blob` as a
+//! `PoolConfig` instead.
 
+use crate::error::StakingError;
+use crate::math::BPS_DENOMINATOR;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
+
+/// Maximum number of checkpoints retained by a staking pool.
+///
+/// Once the buffer is full, recording a new checkpoint overwrites the oldest one.
+pub const MAX_CHECKPOINTS: usize = 16;
+
+/// Maximum `rewards_per_token` a pool can be configured with.
+///
+/// At this rate, `rewards_per_token * total_staked * elapsed_seconds` still fits
+/// in a `u128` for a fully staked `u64` supply over a century.
+pub const MAX_REWARD_RATE: u64 = 1_000_000_000;
+
+/// Discriminator stored in the leading byte of every program account.
+///
+/// A freshly allocated, zeroed account reads as `Uninitialized`, so checking
+/// this byte both rejects accounts of the wrong type and tells whether an
+/// account has been initialized, without deserializing the rest of it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum AccountType {
+    /// A zeroed account that has not been initialized yet.
+    #[default]
+    Uninitialized,
+
+    /// A `PoolStorageAccount`.
+    Pool,
+
+    /// A per-user stake account.
+    ///
+    /// Reserved to pin the discriminator value; no handler creates one yet.
+    User,
+
+    /// A registry of pools.
+    ///
+    /// Reserved to pin the discriminator value; no handler creates one yet.
+    Registry,
+}
+
+impl AccountType {
+    /// Reads the account type from the leading byte of raw account data.
+    ///
+    /// # Errors
+    /// - Returns `StakingError::InvalidAccountData` if `data` is empty or its
+    ///   leading byte is not a known account type.
+    pub fn read(data: &[u8]) -> Result<Self, StakingError> {
+        let discriminator = data.get(..1).ok_or(StakingError::InvalidAccountData)?;
+        Self::try_from_slice(discriminator).map_err(|_| StakingError::InvalidAccountData)
+    }
+}
+
+/// A snapshot of the pool's `total_staked` taken at a given slot.
+///
+/// Checkpoints let off-chain consumers, such as governance voting, look up
+/// the stake weight of the pool at a past slot.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct Checkpoint {
+    /// Slot at which the snapshot was taken.
+    pub slot: u64,
+
+    /// Value of `total_staked` at `slot`.
+    pub total_staked: u64,
+}
+
+/// A role an instruction can require of its signer.
+///
+/// Each role is held by a single key stored on the pool. Use
+/// `PoolStorageAccount::role_holder` to look it up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum Role {
+    /// The pool authority; manages roles and the pool lifecycle.
+    Admin,
+
+    /// Performs routine upkeep such as recording checkpoints.
+    Operator,
+
+    /// Can shut the pool down with `EmergencyShutdown`.
+    Emergency,
+}
+
+/// The static configuration of a staking pool.
+///
+/// Unlike the full `PoolStorageAccount`, this only holds fields set by
+/// `Initialize` or the authority, so clients can read the configuration with
+/// a single fixed-layout deserialization as the pool state grows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PoolConfig {
+    /// Public key of the pool authority.
+    pub pool_authority: Pubkey,
+
+    /// Reward rate per token staked.
+    pub rewards_per_token: u64,
+
+    /// Holder of the `Operator` role.
+    pub operator: Pubkey,
+
+    /// Holder of the `Emergency` role.
+    pub emergency: Pubkey,
+}
+
+impl PoolConfig {
+    /// Serialized size of a `PoolConfig` in bytes.
+    pub const LEN: usize = 32 + 8 + 32 + 32;
+}
+
+/// The constants compiled into the program, as returned by `LogConfig`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct ProgramConfig {
+    /// Serialized size of a `PoolStorageAccount` in bytes.
+    pub pool_account_len: u64,
+
+    /// Maximum number of checkpoints retained by a staking pool.
+    pub max_checkpoints: u64,
+
+    /// Number of basis points in 100%.
+    pub bps_denominator: u64,
+
+    /// Maximum `rewards_per_token` a pool can be configured with.
+    pub max_reward_rate: u64,
+}
+
+impl ProgramConfig {
+    /// Serialized size of a `ProgramConfig` in bytes.
+    pub const LEN: usize = 8 + 8 + 8 + 8;
+
+    /// Returns the constants of this build of the program.
+    pub const fn current() -> Self {
+        Self {
+            pool_account_len: PoolStorageAccount::LEN as u64,
+            max_checkpoints: MAX_CHECKPOINTS as u64,
+            bps_denominator: BPS_DENOMINATOR,
+            max_reward_rate: MAX_REWARD_RATE,
+        }
+    }
+}
 
 /// Represents the state of a staking pool in a Solana program.
 ///
@@ -36,8 +180,11 @@ use solana_program::pubkey::Pubkey;
 /// - `rewards_per_token` should be recalculated and updated as rewards are distributed
 ///   or additional tokens are staked.
 
-#[derive(Debug, BorshDeserialize, BorshSerialize)]
+#[derive(Debug, Default, BorshDeserialize, BorshSerialize)]
 pub struct PoolStorageAccount {
+    /// Account discriminator, `AccountType::Pool` once `Initialize` has run.
+    pub account_type: AccountType,
+
     /// Public key of the authority or owner of the staking pool.
     ///
     /// This is typically the program or wallet responsible for managing
@@ -63,4 +210,120 @@ pub struct PoolStorageAccount {
     /// staked in the pool. It is typically calculated based on pool parameters
     /// and updated periodically.
     pub rewards_per_token: u64,
+
+    /// Ring buffer of the most recent `total_staked` checkpoints.
+    ///
+    /// Slot `checkpoint_count % MAX_CHECKPOINTS` is the next one to be written.
+    /// Use `recent_checkpoints` to read them in chronological order.
+    pub checkpoints: [Checkpoint; MAX_CHECKPOINTS],
+
+    /// Total number of checkpoints ever recorded, including overwritten ones.
+    pub checkpoint_count: u64,
+
+    /// Authority proposed by `ProposeAuthority`, awaiting `AcceptAuthority`.
+    ///
+    /// `Pubkey::default()` when no handoff is pending. The handoff only completes
+    /// once this key signs, so a mistyped address can never become the authority.
+    pub pending_authority: Pubkey,
+
+    /// Holder of the `Operator` role, set to the authority by `Initialize`.
+    pub operator: Pubkey,
+
+    /// Holder of the `Emergency` role, set to the authority by `Initialize`.
+    pub emergency: Pubkey,
+
+    /// Whether the pool has been shut down by `EmergencyShutdown`.
+    ///
+    /// This is a one-way switch; once set, the pool rejects every instruction
+    /// that changes its state except `ClosePool`.
+    pub is_shutdown: bool,
+}
+
+impl PoolStorageAccount {
+    /// Serialized size of a `PoolStorageAccount` in bytes.
+    pub const LEN: usize = 1 + 32 + 8 + 8 + 8 + MAX_CHECKPOINTS * (8 + 8) + 8 + 32 + 32 + 32 + 1;
+
+    /// Records the current `total_staked` as a checkpoint at `slot`.
+    ///
+    /// If the latest checkpoint was taken at the same slot, it is updated in
+    /// place so there is at most one checkpoint per slot. Otherwise the
+    /// checkpoint is appended, overwriting the oldest one when the buffer is full.
+    ///
+    /// # Errors
+    /// - Returns `StakingError::ArithmeticOverflow` if `checkpoint_count` overflows.
+    pub fn push_checkpoint(&mut self, slot: u64) -> Result<(), StakingError> {
+        let checkpoint = Checkpoint {
+            slot,
+            total_staked: self.total_staked,
+        };
+
+        if let Some(last) = self.recent_checkpoints().last() {
+            if last.slot == slot {
+                let index = (self.checkpoint_count - 1) as usize % MAX_CHECKPOINTS;
+                self.checkpoints[index] = checkpoint;
+                return Ok(());
+            }
+        }
+
+        let index = (self.checkpoint_count % MAX_CHECKPOINTS as u64) as usize;
+        self.checkpoints[index] = checkpoint;
+        self.checkpoint_count = self
+            .checkpoint_count
+            .checked_add(1)
+            .ok_or(StakingError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Returns the key holding `role` on this pool.
+    ///
+    /// The `Admin` role is held by `pool_authority`.
+    pub fn role_holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Admin => self.pool_authority,
+            Role::Operator => self.operator,
+            Role::Emergency => self.emergency,
+        }
+    }
+
+    /// Returns the static configuration of the pool.
+    pub fn config(&self) -> PoolConfig {
+        PoolConfig {
+            pool_authority: self.pool_authority,
+            rewards_per_token: self.rewards_per_token,
+            operator: self.operator,
+            emergency: self.emergency,
+        }
+    }
+
+    /// Returns the static configuration of the pool as a Borsh-encoded `PoolConfig`.
+    pub fn config_blob(&self) -> Vec<u8> {
+        borsh::to_vec(&self.config()).expect("serializing into a Vec cannot fail")
+    }
+
+    /// Returns the retained checkpoints, ordered from oldest to newest.
+    pub fn recent_checkpoints(&self) -> Vec<Checkpoint> {
+        let len = self.checkpoint_count.min(MAX_CHECKPOINTS as u64) as usize;
+        let start = (self.checkpoint_count - len as u64) as usize;
+
+        (start..start + len)
+            .map(|i| self.checkpoints[i % MAX_CHECKPOINTS])
+            .collect()
+    }
+
+    /// Returns the latest retained checkpoint taken at or before `slot`.
+    ///
+    /// Returns `None` if `slot` predates every retained checkpoint.
+    pub fn checkpoint_at(&self, slot: u64) -> Option<Checkpoint> {
+        self.recent_checkpoints()
+            .into_iter()
+            .rev()
+            .find(|checkpoint| checkpoint.slot <= slot)
+    }
+}
+
+impl IsInitialized for PoolStorageAccount {
+    fn is_initialized(&self) -> bool {
+        self.account_type == AccountType::Pool
+    }
 }
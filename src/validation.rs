@@ -0,0 +1,175 @@
+//! # Account Validation Module
+//!
+//! This module contains reusable checks on the accounts passed to the
+//! staking pool instructions. Each check returns a descriptive
+//! `StakingError` so handlers can fail early with a precise code.
+//!
+//! ## Key Functions
+//!
+//! - `require_executable`: Ensures an account is a program before invoking it.
+//! - `require_distinct`: Ensures no account is passed in more than one slot.
+//! - `require_signer`: Ensures an account signed the transaction.
+//! - `require_owner`: Ensures an account is owned by the expected program.
+//! - `require_role`: Ensures an account holds a role on the pool.
+//! - `require_active`: Ensures the pool has not been shut down.
+//! - `load_account`: Checks ownership, length and type, then deserializes an account.
+
+use crate::error::{StakingError, StakingResult};
+use crate::state::{AccountType, PoolStorageAccount, Role};
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Ensures that `program_account` is an executable program account.
+///
+/// This must be called on the target program account before any `invoke` or
+/// `invoke_signed`, so a data account passed in place of a program is rejected
+/// with a clear error rather than an opaque CPI failure.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidProgram` if the account is not executable.
+pub fn require_executable(program_account: &AccountInfo) -> ProgramResult {
+    if !program_account.executable {
+        return Err(StakingError::InvalidProgram.into());
+    }
+
+    Ok(())
+}
+
+/// Ensures that all of the given account keys are distinct.
+///
+/// Handlers taking several accounts must call this before borrowing any
+/// account data, so the same account passed in two slots is rejected instead
+/// of aliasing state, for example the pool being treated as its own authority.
+/// Both slots share one `RefCell`, so without this check overlapping borrows
+/// of the account would panic rather than fail with a clean error.
+///
+/// # Errors
+/// - Returns `StakingError::DuplicateAccount` if any key appears more than once.
+pub fn require_distinct(accounts: &[&Pubkey]) -> ProgramResult {
+    for (i, key) in accounts.iter().enumerate() {
+        if accounts[i + 1..].contains(key) {
+            return Err(StakingError::DuplicateAccount.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensures that `account` signed the transaction.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidSigner` if the account is not a signer.
+pub fn require_signer(account: &AccountInfo) -> ProgramResult {
+    if !account.is_signer {
+        msg!("Account {} must sign the transaction", account.key);
+        return Err(StakingError::InvalidSigner.into());
+    }
+
+    Ok(())
+}
+
+/// Ensures that `account` is owned by the `owner` program.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidOwner` if the account has a different owner.
+pub fn require_owner(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner != owner {
+        msg!(
+            "Account {} is owned by {}, expected {}",
+            account.key,
+            account.owner,
+            owner
+        );
+        return Err(StakingError::InvalidOwner.into());
+    }
+
+    Ok(())
+}
+
+/// Ensures that `account` holds `role` on `pool`.
+///
+/// This only compares keys; call `require_signer` to check the signature.
+///
+/// # Errors
+/// - Returns `StakingError::Unauthorized` if the account does not hold the role.
+pub fn require_role(pool: &PoolStorageAccount, account: &AccountInfo, role: Role) -> ProgramResult {
+    let holder = pool.role_holder(role);
+    if holder != *account.key {
+        msg!(
+            "Account {} does not hold the {:?} role, held by {}",
+            account.key,
+            role,
+            holder
+        );
+        return Err(StakingError::Unauthorized.into());
+    }
+
+    Ok(())
+}
+
+/// Ensures that `pool` has not been shut down.
+///
+/// # Errors
+/// - Returns `StakingError::PoolShutdown` if `EmergencyShutdown` has been run on the pool.
+pub fn require_active(pool: &PoolStorageAccount) -> ProgramResult {
+    if pool.is_shutdown {
+        msg!("Pool has been shut down");
+        return Err(StakingError::PoolShutdown.into());
+    }
+
+    Ok(())
+}
+
+/// Deserializes the data of an account owned by `program_id`.
+///
+/// The data must be exactly `expected_len` bytes and start with the
+/// `account_type` discriminator, so an account of another type is rejected
+/// from its leading byte before Borsh reads the rest of it.
+///
+/// # Errors
+/// - Returns `StakingError::InvalidOwner` if the account is not owned by `program_id`.
+/// - Returns `ProgramError::UninitializedAccount` if a type other than
+///   `AccountType::Uninitialized` is expected but the account is uninitialized.
+/// - Returns `StakingError::AlreadyInitialized` if `AccountType::Uninitialized`
+///   is expected but the account is initialized.
+/// - Returns `StakingError::InvalidAccountData` if the data has the wrong length,
+///   holds another account type, or does not deserialize into `T`.
+pub fn load_account<T: BorshDeserialize>(
+    account: &AccountInfo,
+    program_id: &Pubkey,
+    account_type: AccountType,
+    expected_len: usize,
+) -> StakingResult<T> {
+    require_owner(account, program_id)?;
+
+    let data = account.data.borrow();
+    if data.len() != expected_len {
+        msg!(
+            "Account {} has {} bytes of data, expected {}",
+            account.key,
+            data.len(),
+            expected_len
+        );
+        return Err(StakingError::InvalidAccountData.into());
+    }
+
+    let found = AccountType::read(&data)?;
+    if found != account_type {
+        msg!(
+            "Account {} is {:?}, expected {:?}",
+            account.key,
+            found,
+            account_type
+        );
+        return Err(match (account_type, found) {
+            (_, AccountType::Uninitialized) => ProgramError::UninitializedAccount,
+            (AccountType::Uninitialized, _) => StakingError::AlreadyInitialized.into(),
+            _ => StakingError::InvalidAccountData.into(),
+        });
+    }
+
+    T::try_from_slice(&data).map_err(|_| StakingError::InvalidAccountData.into())
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::signature::Signer;
+use stakingdapp::{error::StakingError, instruction::Instruction as StakingInstruction};
+
+#[tokio::test]
+async fn record_checkpoint_by_authority_succeeds() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.total_staked = 1_000;
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::RecordCheckpoint {},
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let checkpoints = load_pool(&banks_client, pool).await.recent_checkpoints();
+    assert_eq!(checkpoints.len(), 1);
+    assert_eq!(checkpoints[0].total_staked, 1_000);
+}
+
+#[tokio::test]
+async fn missing_signature_is_invalid_signer() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[instruction(
+            program_id,
+            &StakingInstruction::RecordCheckpoint {},
+            vec![
+                AccountMeta::new_readonly(authority.pubkey(), false),
+                AccountMeta::new(pool, false),
+            ],
+        )],
+        &payer,
+        &[],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::InvalidSigner);
+}
+
+#[tokio::test]
+async fn wrong_authority_is_unauthorized() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let intruder = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::RecordCheckpoint {},
+            intruder.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&intruder],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+}
+
+#[tokio::test]
+async fn foreign_pool_account_is_invalid_owner() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+
+    // a well-formed pool owned by some other program
+    let pool = add_pool(
+        &mut program_test,
+        Pubkey::new_unique(),
+        authority.pubkey(),
+        |_| {},
+    );
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::RecordCheckpoint {},
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::InvalidOwner);
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use stakingdapp::{error::StakingError, instruction::Instruction as StakingInstruction};
+
+#[tokio::test]
+async fn propose_then_accept_transfers_authority() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let new_authority = Keypair::new();
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let propose = pool_ix(
+        program_id,
+        StakingInstruction::ProposeAuthority {
+            new: new_authority.pubkey(),
+        },
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[propose], &payer, &[&authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    // proposing alone doesn't change the authority
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.pool_authority, authority.pubkey());
+    assert_eq!(pool_state.pending_authority, new_authority.pubkey());
+
+    let accept = pool_ix(
+        program_id,
+        StakingInstruction::AcceptAuthority {},
+        new_authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[accept], &payer, &[&new_authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.pool_authority, new_authority.pubkey());
+    assert_eq!(pool_state.pending_authority, Pubkey::default());
+    // roles the old authority still held move with the handoff
+    assert_eq!(pool_state.operator, new_authority.pubkey());
+    assert_eq!(pool_state.emergency, new_authority.pubkey());
+
+    // so the old authority can no longer shut the pool down
+    let shutdown = pool_ix(
+        program_id,
+        StakingInstruction::EmergencyShutdown {},
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[shutdown], &payer, &[&authority], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+}
+
+#[tokio::test]
+async fn accept_keeps_roles_delegated_to_other_keys() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let new_authority = Keypair::new();
+    let operator = Pubkey::new_unique();
+    let emergency = Pubkey::new_unique();
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.pending_authority = new_authority.pubkey();
+        pool.operator = operator;
+        pool.emergency = emergency;
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let accept = pool_ix(
+        program_id,
+        StakingInstruction::AcceptAuthority {},
+        new_authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[accept], &payer, &[&new_authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.pool_authority, new_authority.pubkey());
+    assert_eq!(pool_state.operator, operator);
+    assert_eq!(pool_state.emergency, emergency);
+}
+
+#[tokio::test]
+async fn accept_by_wrong_key_is_rejected() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let new_authority = Keypair::new();
+    let intruder = Keypair::new();
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.pending_authority = new_authority.pubkey();
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let accept = pool_ix(
+        program_id,
+        StakingInstruction::AcceptAuthority {},
+        intruder.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[accept], &payer, &[&intruder], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.pool_authority, authority.pubkey());
+    assert_eq!(pool_state.pending_authority, new_authority.pubkey());
+}
+
+#[tokio::test]
+async fn cancel_clears_pending_authority() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let new_authority = Keypair::new();
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.pending_authority = new_authority.pubkey();
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let cancel = pool_ix(
+        program_id,
+        StakingInstruction::CancelAuthority {},
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[cancel], &payer, &[&authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        load_pool(&banks_client, pool).await.pending_authority,
+        Pubkey::default()
+    );
+
+    // the cancelled proposal can no longer be accepted
+    let accept = pool_ix(
+        program_id,
+        StakingInstruction::AcceptAuthority {},
+        new_authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[accept], &payer, &[&new_authority], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+    assert_eq!(
+        load_pool(&banks_client, pool).await.pool_authority,
+        authority.pubkey()
+    );
+}
+
+#[tokio::test]
+async fn propose_by_non_authority_is_rejected() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let intruder = Keypair::new();
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let propose = pool_ix(
+        program_id,
+        StakingInstruction::ProposeAuthority {
+            new: intruder.pubkey(),
+        },
+        intruder.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[propose], &payer, &[&intruder], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+}
@@ -0,0 +1,96 @@
+use borsh::BorshDeserialize;
+use stakingdapp::state::{Checkpoint, PoolStorageAccount, MAX_CHECKPOINTS};
+
+fn checkpoint(slot: u64, total_staked: u64) -> Checkpoint {
+    Checkpoint { slot, total_staked }
+}
+
+#[test]
+fn zeroed_pool_has_no_checkpoints() {
+    let pool = PoolStorageAccount::try_from_slice(&[0u8; PoolStorageAccount::LEN]).unwrap();
+    assert!(pool.recent_checkpoints().is_empty());
+}
+
+#[test]
+fn push_checkpoint_records_total_staked_in_order() {
+    let mut pool = PoolStorageAccount::default();
+
+    for slot in 1..=3 {
+        pool.total_staked = slot * 100;
+        pool.push_checkpoint(slot).unwrap();
+    }
+
+    assert_eq!(pool.checkpoint_count, 3);
+    assert_eq!(
+        pool.recent_checkpoints(),
+        vec![checkpoint(1, 100), checkpoint(2, 200), checkpoint(3, 300)]
+    );
+}
+
+#[test]
+fn push_checkpoint_in_same_slot_overwrites_latest() {
+    let mut pool = PoolStorageAccount::default();
+    pool.push_checkpoint(7).unwrap();
+
+    pool.total_staked = 500;
+    pool.push_checkpoint(7).unwrap();
+
+    assert_eq!(pool.checkpoint_count, 1);
+    assert_eq!(pool.recent_checkpoints(), vec![checkpoint(7, 500)]);
+}
+
+#[test]
+fn push_checkpoint_wraps_and_keeps_most_recent() {
+    let mut pool = PoolStorageAccount::default();
+    let pushed = MAX_CHECKPOINTS as u64 + 5;
+
+    for slot in 1..=pushed {
+        pool.total_staked = slot;
+        pool.push_checkpoint(slot).unwrap();
+    }
+
+    let expected: Vec<Checkpoint> = (6..=pushed).map(|slot| checkpoint(slot, slot)).collect();
+    assert_eq!(pool.checkpoint_count, pushed);
+    assert_eq!(pool.recent_checkpoints().len(), MAX_CHECKPOINTS);
+    assert_eq!(pool.recent_checkpoints(), expected);
+}
+
+#[test]
+fn checkpoints_survive_serialization() {
+    let mut pool = PoolStorageAccount::default();
+    for slot in 1..=MAX_CHECKPOINTS as u64 + 1 {
+        pool.push_checkpoint(slot).unwrap();
+    }
+
+    let bytes = borsh::to_vec(&pool).unwrap();
+    assert_eq!(bytes.len(), PoolStorageAccount::LEN);
+
+    let decoded = PoolStorageAccount::try_from_slice(&bytes).unwrap();
+    assert_eq!(decoded.recent_checkpoints(), pool.recent_checkpoints());
+}
+
+#[test]
+fn checkpoint_at_returns_latest_prior_checkpoint() {
+    let mut pool = PoolStorageAccount::default();
+    for slot in [10, 20, 30] {
+        pool.total_staked = slot * 100;
+        pool.push_checkpoint(slot).unwrap();
+    }
+
+    assert_eq!(pool.checkpoint_at(20), Some(checkpoint(20, 2000)));
+    assert_eq!(pool.checkpoint_at(25), Some(checkpoint(20, 2000)));
+    assert_eq!(pool.checkpoint_at(u64::MAX), Some(checkpoint(30, 3000)));
+    assert_eq!(pool.checkpoint_at(9), None);
+}
+
+#[test]
+fn checkpoint_at_ignores_overwritten_checkpoints() {
+    let mut pool = PoolStorageAccount::default();
+    for slot in 1..=MAX_CHECKPOINTS as u64 + 1 {
+        pool.push_checkpoint(slot).unwrap();
+    }
+
+    // slot 1 was overwritten, so nothing retained is that old
+    assert_eq!(pool.checkpoint_at(1), None);
+    assert_eq!(pool.checkpoint_at(2), Some(checkpoint(2, 0)));
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "client")]
+use solana_compute_budget_interface::{self as compute_budget, ComputeBudgetInstruction};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use stakingdapp::{
+    client::{
+        compute_unit_limit, effective_apr, format_amount, pool_account_size,
+        with_compute_unit_limit, CHECKPOINT_COMPUTE_UNITS, DEFAULT_COMPUTE_UNITS,
+    },
+    instruction::Instruction as StakingInstruction,
+    state::PoolStorageAccount,
+};
+
+const SECONDS_PER_YEAR: i64 = 31_536_000;
+
+fn pool(rewards_per_token: u64, total_staked: u64) -> PoolStorageAccount {
+    PoolStorageAccount {
+        pool_authority: Pubkey::new_unique(),
+        total_staked,
+        user_count: 1,
+        rewards_per_token,
+        ..Default::default()
+    }
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= expected.abs() * 1e-12,
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn effective_apr_of_empty_pool_is_zero() {
+    assert_eq!(effective_apr(&pool(42, 0), SECONDS_PER_YEAR), 0.0);
+}
+
+#[test]
+fn effective_apr_without_rewards_is_zero() {
+    assert_eq!(effective_apr(&pool(0, 1_000_000), SECONDS_PER_YEAR), 0.0);
+}
+
+#[test]
+fn effective_apr_annualizes_rate() {
+    assert_close(
+        effective_apr(&pool(1, 1_000_000), SECONDS_PER_YEAR),
+        31_536_000.0,
+    );
+    assert_close(effective_apr(&pool(3, 500), 3_600), 10_800.0);
+}
+
+#[test]
+fn effective_apr_does_not_depend_on_pool_size() {
+    let small = effective_apr(&pool(5, 10), SECONDS_PER_YEAR);
+    let large = effective_apr(&pool(5, 10_000_000_000), SECONDS_PER_YEAR);
+    assert_close(small, large);
+}
+
+#[test]
+fn effective_apr_with_non_positive_year_is_zero() {
+    assert_eq!(effective_apr(&pool(42, 1_000), 0), 0.0);
+    assert_eq!(effective_apr(&pool(42, 1_000), -1), 0.0);
+}
+
+#[test]
+fn format_amount_renders_whole_and_fractional_tokens() {
+    assert_eq!(format_amount(1_500_000, 6), "1.5");
+    assert_eq!(format_amount(1_000_000, 6), "1");
+    assert_eq!(format_amount(1_234_567_890, 9), "1.23456789");
+    assert_eq!(format_amount(42_000_000_000, 9), "42");
+}
+
+#[test]
+fn format_amount_renders_less_than_one_token() {
+    assert_eq!(format_amount(1, 6), "0.000001");
+    assert_eq!(format_amount(500, 3), "0.5");
+    assert_eq!(format_amount(50, 3), "0.05");
+}
+
+#[test]
+fn format_amount_renders_zero() {
+    assert_eq!(format_amount(0, 0), "0");
+    assert_eq!(format_amount(0, 6), "0");
+}
+
+#[test]
+fn format_amount_without_decimals() {
+    assert_eq!(format_amount(1_500_000, 0), "1500000");
+    assert_eq!(format_amount(u64::MAX, 0), "18446744073709551615");
+}
+
+#[test]
+fn format_amount_with_more_decimals_than_digits() {
+    assert_eq!(format_amount(u64::MAX, 19), "1.8446744073709551615");
+    assert_eq!(format_amount(u64::MAX, 20), "0.18446744073709551615");
+}
+
+#[test]
+fn pool_account_size_matches_on_chain_layout() {
+    assert_eq!(pool_account_size(), PoolStorageAccount::LEN);
+    assert_eq!(
+        borsh::to_vec(&PoolStorageAccount::default()).unwrap().len(),
+        pool_account_size()
+    );
+}
+
+#[test]
+fn compute_unit_limit_defaults_per_instruction() {
+    assert_eq!(DEFAULT_COMPUTE_UNITS, 20_000);
+    assert_eq!(CHECKPOINT_COMPUTE_UNITS, 40_000);
+
+    assert_eq!(
+        compute_unit_limit(&StakingInstruction::Initialize {
+            rewards_per_token: 1
+        }),
+        DEFAULT_COMPUTE_UNITS
+    );
+    assert_eq!(
+        compute_unit_limit(&StakingInstruction::ClosePool {}),
+        DEFAULT_COMPUTE_UNITS
+    );
+    assert_eq!(
+        compute_unit_limit(&StakingInstruction::RecordCheckpoint {}),
+        CHECKPOINT_COMPUTE_UNITS
+    );
+    assert_eq!(
+        compute_unit_limit(&StakingInstruction::QueryCheckpoint { slot: 1 }),
+        CHECKPOINT_COMPUTE_UNITS
+    );
+}
+
+#[test]
+fn with_compute_unit_limit_prepends_budget_instruction() {
+    let data = StakingInstruction::RecordCheckpoint {};
+    let ix = Instruction::new_with_borsh(Pubkey::new_unique(), &data, vec![]);
+
+    let instructions = with_compute_unit_limit(ix.clone(), compute_unit_limit(&data));
+    assert_eq!(
+        instructions,
+        vec![
+            ComputeBudgetInstruction::set_compute_unit_limit(CHECKPOINT_COMPUTE_UNITS),
+            ix.clone(),
+        ]
+    );
+    assert_eq!(instructions[0].program_id, compute_budget::id());
+
+    // any limit can be requested in place of the default
+    let instructions = with_compute_unit_limit(ix.clone(), 123_456);
+    assert_eq!(
+        instructions[0],
+        ComputeBudgetInstruction::set_compute_unit_limit(123_456)
+    );
+    assert_eq!(instructions[1], ix);
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use stakingdapp::{error::StakingError, instruction::Instruction as StakingInstruction};
+
+#[tokio::test]
+async fn close_pool_reclaims_rent() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::ClosePool {},
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(banks_client.get_account(pool).await.unwrap().is_none());
+    let authority_account = banks_client
+        .get_account(authority.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(authority_account.lamports, 2 * ACCOUNT_LAMPORTS);
+}
+
+#[tokio::test]
+async fn close_pool_refuses_with_remaining_users() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.user_count = 1;
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::ClosePool {},
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::NonZeroBalance);
+
+    assert!(banks_client.get_account(pool).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn close_pool_refuses_with_remaining_stake() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.total_staked = 1;
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::ClosePool {},
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::NonZeroBalance);
+}
+
+#[tokio::test]
+async fn close_pool_refuses_other_signer() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let intruder = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::ClosePool {},
+            intruder.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&intruder],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+}
@@ -0,0 +1,182 @@
+//! Shared setup for the program tests.
+#![allow(dead_code)]
+
+use borsh::BorshDeserialize;
+use solana_program::{instruction::AccountMeta, instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::{processor, BanksClient, BanksClientError, ProgramTest};
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    signature::Keypair,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use stakingdapp::{
+    error::StakingError,
+    instruction::Instruction as StakingInstruction,
+    processor::process,
+    state::{AccountType, PoolStorageAccount},
+};
+
+/// Lamports funding every test account, enough to be rent exempt.
+pub const ACCOUNT_LAMPORTS: u64 = 10_000_000;
+
+/// Creates a `ProgramTest` running the staking program natively.
+pub fn program_test(program_id: Pubkey) -> ProgramTest {
+    ProgramTest::new("stakingdapp", program_id, processor!(process))
+}
+
+/// Adds an initialized pool owned by `authority` and returns its address.
+///
+/// As after `Initialize`, `authority` also holds every other role.
+pub fn add_pool(
+    program_test: &mut ProgramTest,
+    program_id: Pubkey,
+    authority: Pubkey,
+    configure: impl FnOnce(&mut PoolStorageAccount),
+) -> Pubkey {
+    let pool_address = Pubkey::new_unique();
+    add_pool_at(program_test, program_id, pool_address, authority, configure);
+    pool_address
+}
+
+/// Adds an initialized pool owned by `authority` at `address`.
+///
+/// Like `add_pool`, but for a pool whose address must be a keypair's.
+pub fn add_pool_at(
+    program_test: &mut ProgramTest,
+    program_id: Pubkey,
+    address: Pubkey,
+    authority: Pubkey,
+    configure: impl FnOnce(&mut PoolStorageAccount),
+) {
+    let mut pool = PoolStorageAccount {
+        pool_authority: authority,
+        operator: authority,
+        emergency: authority,
+        rewards_per_token: 42,
+        account_type: AccountType::Pool,
+        ..Default::default()
+    };
+    configure(&mut pool);
+
+    add_storage(
+        program_test,
+        program_id,
+        address,
+        borsh::to_vec(&pool).unwrap(),
+    );
+}
+
+/// Adds a zeroed, program-owned storage account ready for `Initialize`.
+pub fn add_uninitialized_pool(program_test: &mut ProgramTest, program_id: Pubkey) -> Pubkey {
+    let pool_address = Pubkey::new_unique();
+    add_storage(
+        program_test,
+        program_id,
+        pool_address,
+        vec![0; PoolStorageAccount::LEN],
+    );
+    pool_address
+}
+
+/// Adds a program-owned storage account at `address` holding `data`.
+///
+/// Pass a keypair's address when the storage account itself has to sign.
+pub fn add_storage(
+    program_test: &mut ProgramTest,
+    program_id: Pubkey,
+    address: Pubkey,
+    data: Vec<u8>,
+) {
+    program_test.add_account(
+        address,
+        Account {
+            lamports: ACCOUNT_LAMPORTS,
+            data,
+            owner: program_id,
+            ..Account::default()
+        },
+    );
+}
+
+/// Adds a system-owned wallet funded with `ACCOUNT_LAMPORTS`.
+pub fn add_wallet(program_test: &mut ProgramTest) -> Keypair {
+    let wallet = Keypair::new();
+    program_test.add_account(
+        wallet.pubkey(),
+        Account {
+            lamports: ACCOUNT_LAMPORTS,
+            ..Account::default()
+        },
+    );
+    wallet
+}
+
+/// Builds a staking program instruction.
+pub fn instruction(
+    program_id: Pubkey,
+    data: &StakingInstruction,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    Instruction::new_with_borsh(program_id, data, accounts)
+}
+
+/// Builds an instruction signed by `signer` against the pool storage account.
+///
+/// This is the account layout of every authority- and role-gated instruction.
+/// The signer is writable so it can also receive the rent reclaimed by `ClosePool`.
+pub fn pool_ix(
+    program_id: Pubkey,
+    data: StakingInstruction,
+    signer: Pubkey,
+    pool: Pubkey,
+) -> Instruction {
+    instruction(
+        program_id,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(pool, false),
+        ],
+    )
+}
+
+/// Fetches and deserializes the pool storage account at `pool`.
+pub async fn load_pool(banks_client: &BanksClient, pool: Pubkey) -> PoolStorageAccount {
+    let data = banks_client.get_account(pool).await.unwrap().unwrap().data;
+    PoolStorageAccount::try_from_slice(&data).unwrap()
+}
+
+/// Signs `instructions` with the payer and `signers`, returning the transaction.
+pub fn transaction(
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+    recent_blockhash: solana_sdk::hash::Hash,
+) -> Transaction {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    )
+}
+
+/// Extracts the custom error code of the first instruction of a failed transaction.
+pub fn instruction_error(err: BanksClientError) -> InstructionError {
+    match err.unwrap() {
+        TransactionError::InstructionError(0, err) => err,
+        err => panic!("unexpected transaction error {err:?}"),
+    }
+}
+
+/// Asserts that a transaction failed with the given `StakingError`.
+pub fn assert_staking_error(err: BanksClientError, expected: StakingError) {
+    assert_eq!(
+        instruction_error(err),
+        InstructionError::Custom(expected as u32)
+    );
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::{signature::Keypair, signature::Signer};
+use stakingdapp::{
+    error::StakingError,
+    instruction::Instruction as StakingInstruction,
+    state::{PoolStorageAccount, Role},
+};
+
+#[tokio::test]
+async fn initialize_rejects_storage_as_its_own_authority() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+
+    // a keypair-backed storage account can sign, so it can fill both slots
+    let storage = Keypair::new();
+    add_storage(
+        &mut program_test,
+        program_id,
+        storage.pubkey(),
+        vec![0; PoolStorageAccount::LEN],
+    );
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[instruction(
+            program_id,
+            &StakingInstruction::Initialize {
+                rewards_per_token: 42,
+            },
+            vec![
+                AccountMeta::new(storage.pubkey(), true),
+                AccountMeta::new(storage.pubkey(), false),
+            ],
+        )],
+        &payer,
+        &[&storage],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::DuplicateAccount);
+}
+
+#[tokio::test]
+async fn pool_handlers_reject_pool_in_both_slots() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+
+    // the pool holds every role itself, so only the duplicate check can reject it
+    let storage = Keypair::new();
+    add_pool_at(
+        &mut program_test,
+        program_id,
+        storage.pubkey(),
+        storage.pubkey(),
+        |pool| pool.pending_authority = storage.pubkey(),
+    );
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+    let before = banks_client
+        .get_account(storage.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let instructions = [
+        StakingInstruction::RecordCheckpoint {},
+        StakingInstruction::ClosePool {},
+        StakingInstruction::ProposeAuthority {
+            new: Pubkey::new_unique(),
+        },
+        StakingInstruction::AcceptAuthority {},
+        StakingInstruction::CancelAuthority {},
+        StakingInstruction::SetRole {
+            role: Role::Operator,
+            holder: Pubkey::new_unique(),
+        },
+        StakingInstruction::EmergencyShutdown {},
+        StakingInstruction::RefundUninitialized {},
+    ];
+    for data in instructions {
+        let tx = transaction(
+            &[instruction(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(storage.pubkey(), true),
+                    AccountMeta::new(storage.pubkey(), false),
+                ],
+            )],
+            &payer,
+            &[&storage],
+            recent_blockhash,
+        );
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_staking_error(err, StakingError::DuplicateAccount);
+    }
+
+    let account = banks_client
+        .get_account(storage.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data, before.data);
+    assert_eq!(account.lamports, before.lamports);
+}
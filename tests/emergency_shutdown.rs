@@ -0,0 +1,104 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use stakingdapp::{
+    error::StakingError, instruction::Instruction as StakingInstruction, state::Role,
+};
+
+#[tokio::test]
+async fn only_emergency_role_can_shut_down() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let emergency = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.emergency = emergency.pubkey();
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let shutdown = |signer: Pubkey| {
+        pool_ix(
+            program_id,
+            StakingInstruction::EmergencyShutdown {},
+            signer,
+            pool,
+        )
+    };
+
+    // the admin doesn't hold the emergency role
+    let tx = transaction(
+        &[shutdown(authority.pubkey())],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+    assert!(!load_pool(&banks_client, pool).await.is_shutdown);
+
+    let tx = transaction(
+        &[shutdown(emergency.pubkey())],
+        &payer,
+        &[&emergency],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+    assert!(load_pool(&banks_client, pool).await.is_shutdown);
+}
+
+#[tokio::test]
+async fn shut_down_pool_rejects_state_changes() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.pending_authority = pool.pool_authority;
+        pool.is_shutdown = true;
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let blocked = [
+        StakingInstruction::RecordCheckpoint {},
+        StakingInstruction::ProposeAuthority {
+            new: Pubkey::new_unique(),
+        },
+        StakingInstruction::AcceptAuthority {},
+        StakingInstruction::CancelAuthority {},
+        StakingInstruction::SetRole {
+            role: Role::Operator,
+            holder: Pubkey::new_unique(),
+        },
+        StakingInstruction::EmergencyShutdown {},
+    ];
+    for data in blocked {
+        let ix = pool_ix(program_id, data, authority.pubkey(), pool);
+        let tx = transaction(&[ix], &payer, &[&authority], recent_blockhash);
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_staking_error(err, StakingError::PoolShutdown);
+    }
+}
+
+#[tokio::test]
+async fn shut_down_pool_can_still_be_closed() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.is_shutdown = true;
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let close = pool_ix(
+        program_id,
+        StakingInstruction::ClosePool {},
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[close], &payer, &[&authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(banks_client.get_account(pool).await.unwrap().is_none());
+}
@@ -0,0 +1,44 @@
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+use stakingdapp::{
+    error::{StakingError, StakingResult},
+    state::PoolStorageAccount,
+};
+
+fn narrow(value: u128) -> StakingResult<u64> {
+    let narrowed = u64::try_from(value).map_err(StakingError::from)?;
+    Ok(narrowed)
+}
+
+fn load_pool(data: &[u8]) -> StakingResult<PoolStorageAccount> {
+    Ok(PoolStorageAccount::try_from_slice(data)?)
+}
+
+#[test]
+fn staking_error_converts_to_custom_code() {
+    assert_eq!(
+        ProgramError::from(StakingError::InvalidInstruction),
+        ProgramError::Custom(0)
+    );
+    assert_eq!(
+        ProgramError::from(StakingError::ArithmeticOverflow),
+        ProgramError::Custom(StakingError::ArithmeticOverflow as u32)
+    );
+}
+
+#[test]
+fn integer_narrowing_converts_to_arithmetic_overflow() {
+    assert_eq!(narrow(7), Ok(7));
+    assert_eq!(
+        narrow(u128::MAX),
+        Err(StakingError::ArithmeticOverflow.into())
+    );
+}
+
+#[test]
+fn borsh_error_converts_to_borsh_io_error() {
+    assert!(matches!(
+        load_pool(&[0u8; 3]),
+        Err(ProgramError::BorshIoError(_))
+    ));
+}
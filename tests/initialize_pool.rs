@@ -8,7 +8,10 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use solana_validator::test_validator::TestValidatorGenesis;
-use stakingapp::{instruction::Instruction as StakingInstruction, state::PoolStorageAccount};
+use stakingapp::{
+    instruction::Instruction as StakingInstruction,
+    state::{AccountType, PoolStorageAccount},
+};
 
 #[test]
 fn initialize_pool() {
@@ -44,14 +47,14 @@ fn initialize_pool() {
             .send_and_confirm_transaction(&airdrop_pool_owner_tx)
             .unwrap();
 
-        const POOL_STORAGE_TOTAL_BYTES: usize = 32 + 8 + 8 + 8 + 1; // https://www.anchor-lang.com/docs/space
+        const POOL_STORAGE_TOTAL_BYTES: usize = PoolStorageAccount::LEN;
         let rent_exempt_balance = rpc_client
             .get_minimum_balance_for_rent_exemption(POOL_STORAGE_TOTAL_BYTES)
             .unwrap();
 
         // Create a pool storage account owned by program_id
-        // Allocate 57 bytes of the storage
-        // Transfer enough SOL from pool authority to rent exempt 57 bytes
+        // Allocate PoolStorageAccount::LEN bytes of the storage
+        // Transfer enough SOL from pool authority to rent exempt the storage
         let create_pool_storage_account_tx = system_transaction::create_account(
             &pool_authority,
             &pool_storage_account,
@@ -121,5 +124,5 @@ fn initialize_pool() {
     assert_eq!(pool_storage.total_staked, 0);
     assert_eq!(pool_storage.user_count, 0);
     assert_eq!(pool_storage.rewards_per_token, 42);
-    assert!(pool_storage.is_initialized);
+    assert_eq!(pool_storage.account_type, AccountType::Pool);
 }
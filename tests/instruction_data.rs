@@ -0,0 +1,43 @@
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use stakingdapp::{error::StakingError, instruction::Instruction, processor::process};
+
+/// Runs `data` through the processor with no accounts.
+fn process_data(data: &[u8]) -> Result<(), ProgramError> {
+    process(&Pubkey::new_unique(), &[], data)
+}
+
+#[test]
+fn process_accepts_exact_instruction_data() {
+    let data = borsh::to_vec(&Instruction::LogConfig {}).unwrap();
+
+    assert_eq!(process_data(&data), Ok(()));
+}
+
+#[test]
+fn process_rejects_trailing_bytes() {
+    let mut data = borsh::to_vec(&Instruction::LogConfig {}).unwrap();
+    data.extend_from_slice(&[0xde, 0xad]);
+
+    assert_eq!(
+        process_data(&data),
+        Err(StakingError::InvalidInstruction.into())
+    );
+}
+
+#[test]
+fn process_rejects_truncated_instruction_data() {
+    let mut data = borsh::to_vec(&Instruction::Initialize {
+        rewards_per_token: 42,
+    })
+    .unwrap();
+    data.pop();
+
+    assert_eq!(
+        process_data(&data),
+        Err(StakingError::InvalidInstruction.into())
+    );
+    assert_eq!(
+        process_data(&[]),
+        Err(StakingError::InvalidInstruction.into())
+    );
+}
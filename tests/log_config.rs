@@ -0,0 +1,42 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use borsh::BorshDeserialize;
+use common::*;
+use solana_program::pubkey::Pubkey;
+use stakingdapp::{
+    instruction::Instruction as StakingInstruction,
+    math::BPS_DENOMINATOR,
+    state::{PoolStorageAccount, ProgramConfig, MAX_CHECKPOINTS, MAX_REWARD_RATE},
+};
+
+#[tokio::test]
+async fn log_config_reports_constants() {
+    let program_id = Pubkey::new_unique();
+    let (banks_client, payer, recent_blockhash) = program_test(program_id).start().await;
+
+    let tx = transaction(
+        &[instruction(
+            program_id,
+            &StakingInstruction::LogConfig {},
+            vec![],
+        )],
+        &payer,
+        &[],
+        recent_blockhash,
+    );
+    let simulation = banks_client.simulate_transaction(tx).await.unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+    let return_data = simulation
+        .simulation_details
+        .unwrap()
+        .return_data
+        .expect("config return data");
+    assert_eq!(return_data.program_id, program_id);
+
+    let config = ProgramConfig::try_from_slice(&return_data.data).unwrap();
+    assert_eq!(config.pool_account_len, PoolStorageAccount::LEN as u64);
+    assert_eq!(config.max_checkpoints, MAX_CHECKPOINTS as u64);
+    assert_eq!(config.bps_denominator, BPS_DENOMINATOR);
+    assert_eq!(config.max_reward_rate, MAX_REWARD_RATE);
+}
@@ -0,0 +1,44 @@
+// `debug_msg!` output itself is not asserted on: the native program-test
+// builtin does not forward `msg!` to the log collector, so without an SBF
+// build there is no log capture to observe. These tests pin the precedence
+// rules and the feature resolution that `debug_msg!` branches on.
+use stakingdapp::logging::{verbosity, VERBOSE};
+
+#[test]
+fn log_quiet_overrides_everything() {
+    for log_verbose in [false, true] {
+        for debug_assertions in [false, true] {
+            assert!(!verbosity(true, log_verbose, debug_assertions));
+        }
+    }
+}
+
+#[test]
+fn log_verbose_enables_dumps_in_release_builds() {
+    assert!(verbosity(false, true, false));
+    assert!(verbosity(false, true, true));
+}
+
+#[test]
+fn build_profile_decides_without_log_features() {
+    assert!(verbosity(false, false, true));
+    assert!(!verbosity(false, false, false));
+}
+
+#[cfg(all(feature = "log_verbose", not(feature = "log_quiet")))]
+#[test]
+fn log_verbose_enables_debug_dumps() {
+    const { assert!(VERBOSE) };
+}
+
+#[cfg(feature = "log_quiet")]
+#[test]
+fn log_quiet_disables_debug_dumps() {
+    const { assert!(!VERBOSE) };
+}
+
+#[cfg(not(any(feature = "log_verbose", feature = "log_quiet")))]
+#[test]
+fn debug_dumps_follow_build_profile_by_default() {
+    assert_eq!(VERBOSE, cfg!(debug_assertions));
+}
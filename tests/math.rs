@@ -0,0 +1,61 @@
+use stakingdapp::{
+    error::StakingError,
+    math::{apply_bps, u128_to_u64_checked},
+};
+
+#[test]
+fn u128_to_u64_checked_keeps_values_in_range() {
+    assert_eq!(u128_to_u64_checked(0), Ok(0));
+    assert_eq!(u128_to_u64_checked(42), Ok(42));
+}
+
+#[test]
+fn u128_to_u64_checked_accepts_u64_max() {
+    assert_eq!(u128_to_u64_checked(u64::MAX as u128), Ok(u64::MAX));
+}
+
+#[test]
+fn u128_to_u64_checked_rejects_values_beyond_u64_max() {
+    assert_eq!(
+        u128_to_u64_checked(u64::MAX as u128 + 1),
+        Err(StakingError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        u128_to_u64_checked(u128::MAX),
+        Err(StakingError::ArithmeticOverflow)
+    );
+}
+
+#[test]
+fn apply_bps_scales_amount() {
+    assert_eq!(apply_bps(1_000_000, 250), Ok(25_000));
+    assert_eq!(apply_bps(1_000_000, 10_000), Ok(1_000_000));
+    assert_eq!(apply_bps(1_000_000, 0), Ok(0));
+}
+
+#[test]
+fn apply_bps_rounds_down() {
+    assert_eq!(apply_bps(9_999, 1), Ok(0));
+    assert_eq!(apply_bps(10_001, 1), Ok(1));
+    assert_eq!(apply_bps(333, 5_000), Ok(166));
+}
+
+#[test]
+fn apply_bps_does_not_overflow_on_u64_max() {
+    assert_eq!(apply_bps(u64::MAX, 10_000), Ok(u64::MAX));
+    assert_eq!(apply_bps(u64::MAX, 5_000), Ok(u64::MAX / 2));
+    assert_eq!(apply_bps(u64::MAX, 1), Ok(u64::MAX / 10_000));
+}
+
+#[test]
+fn apply_bps_accepts_results_just_below_u64_max() {
+    assert_eq!(apply_bps(u64::MAX / 2, 20_000), Ok(u64::MAX - 1));
+}
+
+#[test]
+fn apply_bps_rejects_results_beyond_u64_max() {
+    assert_eq!(
+        apply_bps(u64::MAX, 10_001),
+        Err(StakingError::ArithmeticOverflow)
+    );
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use borsh::BorshDeserialize;
+use common::*;
+use solana_program::{instruction::AccountMeta, instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::signature::Signer;
+use stakingdapp::{
+    error::StakingError, instruction::Instruction as StakingInstruction, state::Checkpoint,
+};
+
+fn query_checkpoint_ix(program_id: Pubkey, pool: Pubkey, slot: u64) -> Instruction {
+    instruction(
+        program_id,
+        &StakingInstruction::QueryCheckpoint { slot },
+        vec![AccountMeta::new_readonly(pool, false)],
+    )
+}
+
+#[tokio::test]
+async fn query_between_checkpoints_returns_earlier_checkpoint() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        for slot in [10, 20, 30] {
+            pool.total_staked = slot * 100;
+            pool.push_checkpoint(slot).unwrap();
+        }
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[query_checkpoint_ix(program_id, pool, 25)],
+        &payer,
+        &[],
+        recent_blockhash,
+    );
+    let simulation = banks_client.simulate_transaction(tx).await.unwrap();
+    assert!(simulation.result.unwrap().is_ok());
+    let return_data = simulation
+        .simulation_details
+        .unwrap()
+        .return_data
+        .expect("checkpoint return data");
+    assert_eq!(return_data.program_id, program_id);
+
+    // slot 25 resolves to the checkpoint taken at slot 20
+    assert_eq!(
+        Checkpoint::try_from_slice(&return_data.data).unwrap(),
+        Checkpoint {
+            slot: 20,
+            total_staked: 2000,
+        }
+    );
+}
+
+#[tokio::test]
+async fn query_before_first_checkpoint_is_no_checkpoint() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.push_checkpoint(10).unwrap();
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[query_checkpoint_ix(program_id, pool, 9)],
+        &payer,
+        &[],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::NoCheckpoint);
+}
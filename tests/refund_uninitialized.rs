@@ -0,0 +1,92 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::signature::{Keypair, Signer};
+use stakingdapp::{
+    error::StakingError,
+    instruction::Instruction as StakingInstruction,
+    state::{AccountType, PoolStorageAccount},
+};
+
+fn refund_ix(
+    program_id: Pubkey,
+    storage: Pubkey,
+    recipient: Pubkey,
+) -> solana_program::instruction::Instruction {
+    instruction(
+        program_id,
+        &StakingInstruction::RefundUninitialized {},
+        vec![
+            AccountMeta::new(storage, true),
+            AccountMeta::new(recipient, false),
+        ],
+    )
+}
+
+#[tokio::test]
+async fn refund_reclaims_wrongly_sized_account() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let recipient = add_wallet(&mut program_test);
+    // too small to ever be initialized as a pool
+    let storage = Keypair::new();
+    add_storage(&mut program_test, program_id, storage.pubkey(), vec![0; 10]);
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[refund_ix(program_id, storage.pubkey(), recipient.pubkey())],
+        &payer,
+        &[&storage],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(banks_client
+        .get_account(storage.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+    let recipient_account = banks_client
+        .get_account(recipient.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(recipient_account.lamports, 2 * ACCOUNT_LAMPORTS);
+}
+
+#[tokio::test]
+async fn refund_refuses_initialized_pool() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let recipient = add_wallet(&mut program_test);
+    let pool = PoolStorageAccount {
+        account_type: AccountType::Pool,
+        ..Default::default()
+    };
+    let storage = Keypair::new();
+    add_storage(
+        &mut program_test,
+        program_id,
+        storage.pubkey(),
+        borsh::to_vec(&pool).unwrap(),
+    );
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[refund_ix(program_id, storage.pubkey(), recipient.pubkey())],
+        &payer,
+        &[&storage],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::AlreadyInitialized);
+
+    let storage_account = banks_client
+        .get_account(storage.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(storage_account.lamports, ACCOUNT_LAMPORTS);
+}
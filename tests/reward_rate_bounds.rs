@@ -0,0 +1,69 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::Signer;
+use stakingdapp::{
+    error::StakingError,
+    instruction::Instruction as StakingInstruction,
+    state::{AccountType, MAX_REWARD_RATE},
+};
+
+#[tokio::test]
+async fn initialize_accepts_max_reward_rate() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_uninitialized_pool(&mut program_test, program_id);
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::Initialize {
+                rewards_per_token: MAX_REWARD_RATE,
+            },
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.account_type, AccountType::Pool);
+    assert_eq!(pool_state.rewards_per_token, MAX_REWARD_RATE);
+}
+
+#[tokio::test]
+async fn initialize_rejects_rate_above_max() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_uninitialized_pool(&mut program_test, program_id);
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let tx = transaction(
+        &[pool_ix(
+            program_id,
+            StakingInstruction::Initialize {
+                rewards_per_token: MAX_REWARD_RATE + 1,
+            },
+            authority.pubkey(),
+            pool,
+        )],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::RateTooHigh);
+
+    assert_eq!(
+        load_pool(&banks_client, pool).await.account_type,
+        AccountType::Uninitialized
+    );
+}
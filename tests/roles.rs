@@ -0,0 +1,147 @@
+#![cfg(feature = "test-bpf")]
+mod common;
+
+use common::*;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use stakingdapp::{
+    error::StakingError, instruction::Instruction as StakingInstruction, state::Role,
+};
+
+#[tokio::test]
+async fn initialize_grants_every_role_to_authority() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_uninitialized_pool(&mut program_test, program_id);
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let initialize = pool_ix(
+        program_id,
+        StakingInstruction::Initialize {
+            rewards_per_token: 42,
+        },
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[initialize], &payer, &[&authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    for role in [Role::Admin, Role::Operator, Role::Emergency] {
+        assert_eq!(pool_state.role_holder(role), authority.pubkey());
+    }
+}
+
+#[tokio::test]
+async fn operator_records_checkpoints_in_place_of_admin() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let operator = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let set_role = pool_ix(
+        program_id,
+        StakingInstruction::SetRole {
+            role: Role::Operator,
+            holder: operator.pubkey(),
+        },
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[set_role], &payer, &[&authority], recent_blockhash);
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(
+        load_pool(&banks_client, pool).await.operator,
+        operator.pubkey()
+    );
+
+    let record = |signer: Pubkey| {
+        pool_ix(
+            program_id,
+            StakingInstruction::RecordCheckpoint {},
+            signer,
+            pool,
+        )
+    };
+
+    let tx = transaction(
+        &[record(operator.pubkey())],
+        &payer,
+        &[&operator],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(tx).await.unwrap();
+    assert_eq!(load_pool(&banks_client, pool).await.checkpoint_count, 1);
+
+    // the admin no longer holds the operator role
+    let tx = transaction(
+        &[record(authority.pubkey())],
+        &payer,
+        &[&authority],
+        recent_blockhash,
+    );
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::Unauthorized);
+}
+
+#[tokio::test]
+async fn operator_cannot_use_admin_instructions() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let operator = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |pool| {
+        pool.operator = operator.pubkey();
+    });
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let admin_instructions = [
+        StakingInstruction::ClosePool {},
+        StakingInstruction::ProposeAuthority {
+            new: operator.pubkey(),
+        },
+        StakingInstruction::CancelAuthority {},
+        StakingInstruction::SetRole {
+            role: Role::Emergency,
+            holder: operator.pubkey(),
+        },
+    ];
+    for data in admin_instructions {
+        let ix = pool_ix(program_id, data, operator.pubkey(), pool);
+        let tx = transaction(&[ix], &payer, &[&operator], recent_blockhash);
+        let err = banks_client.process_transaction(tx).await.unwrap_err();
+        assert_staking_error(err, StakingError::Unauthorized);
+    }
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.pool_authority, authority.pubkey());
+    assert_eq!(pool_state.emergency, authority.pubkey());
+}
+
+#[tokio::test]
+async fn set_role_rejects_admin() {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = program_test(program_id);
+    let authority = add_wallet(&mut program_test);
+    let pool = add_pool(&mut program_test, program_id, authority.pubkey(), |_| {});
+    let (banks_client, payer, recent_blockhash) = program_test.start().await;
+
+    let set_role = pool_ix(
+        program_id,
+        StakingInstruction::SetRole {
+            role: Role::Admin,
+            holder: Keypair::new().pubkey(),
+        },
+        authority.pubkey(),
+        pool,
+    );
+    let tx = transaction(&[set_role], &payer, &[&authority], recent_blockhash);
+    let err = banks_client.process_transaction(tx).await.unwrap_err();
+    assert_staking_error(err, StakingError::InvalidInstruction);
+
+    let pool_state = load_pool(&banks_client, pool).await;
+    assert_eq!(pool_state.pool_authority, authority.pubkey());
+}
@@ -0,0 +1,271 @@
+use borsh::BorshSerialize;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use stakingdapp::{
+    error::{StakingError, StakingResult},
+    state::{AccountType, PoolStorageAccount, Role},
+    validation::{
+        load_account, require_active, require_distinct, require_executable, require_role,
+    },
+};
+
+/// Loads `data` as a pool of `account_type` through `load_account`, as if
+/// owned by `owner`.
+fn load_pool(
+    data: &mut [u8],
+    owner: &Pubkey,
+    program_id: &Pubkey,
+    account_type: AccountType,
+) -> StakingResult<PoolStorageAccount> {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1;
+    let account = AccountInfo::new(&key, false, true, &mut lamports, data, owner, false, 0);
+
+    load_account(&account, program_id, account_type, PoolStorageAccount::LEN)
+}
+
+fn pool_bytes() -> Vec<u8> {
+    let pool = PoolStorageAccount {
+        pool_authority: Pubkey::new_unique(),
+        rewards_per_token: 5,
+        account_type: AccountType::Pool,
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    pool.serialize(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn require_executable_accepts_program_account() {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let (mut lamports, mut data) = (1, vec![]);
+    let program = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        true,
+        0,
+    );
+
+    assert_eq!(require_executable(&program), Ok(()));
+}
+
+#[test]
+fn require_executable_rejects_data_account() {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let (mut lamports, mut data) = (1, vec![0u8; 8]);
+    let not_a_program = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    assert_eq!(
+        require_executable(&not_a_program),
+        Err(ProgramError::from(StakingError::InvalidProgram))
+    );
+}
+
+#[test]
+fn require_distinct_accepts_distinct_accounts() {
+    let (a, b, c) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    assert_eq!(require_distinct(&[]), Ok(()));
+    assert_eq!(require_distinct(&[&a]), Ok(()));
+    assert_eq!(require_distinct(&[&a, &b, &c]), Ok(()));
+}
+
+#[test]
+fn require_distinct_rejects_duplicate_accounts() {
+    let (a, b, c) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let duplicate = Err(ProgramError::from(StakingError::DuplicateAccount));
+
+    assert_eq!(require_distinct(&[&a, &a]), duplicate);
+    assert_eq!(require_distinct(&[&a, &b, &a]), duplicate);
+    assert_eq!(require_distinct(&[&a, &b, &c, &c]), duplicate);
+}
+
+#[test]
+fn load_account_deserializes_valid_pool() {
+    let program_id = Pubkey::new_unique();
+    let mut data = pool_bytes();
+
+    let pool = load_pool(&mut data, &program_id, &program_id, AccountType::Pool).unwrap();
+    assert_eq!(pool.account_type, AccountType::Pool);
+    assert_eq!(pool.rewards_per_token, 5);
+}
+
+#[test]
+fn load_account_rejects_foreign_owner() {
+    let program_id = Pubkey::new_unique();
+    let mut data = pool_bytes();
+
+    assert_eq!(
+        load_pool(
+            &mut data,
+            &Pubkey::new_unique(),
+            &program_id,
+            AccountType::Pool
+        )
+        .unwrap_err(),
+        StakingError::InvalidOwner.into()
+    );
+}
+
+#[test]
+fn load_account_rejects_wrong_length() {
+    let program_id = Pubkey::new_unique();
+    let invalid = ProgramError::from(StakingError::InvalidAccountData);
+
+    let mut short = pool_bytes();
+    short.pop();
+    assert_eq!(
+        load_pool(&mut short, &program_id, &program_id, AccountType::Pool).unwrap_err(),
+        invalid
+    );
+
+    let mut long = pool_bytes();
+    long.push(0);
+    assert_eq!(
+        load_pool(&mut long, &program_id, &program_id, AccountType::Pool).unwrap_err(),
+        invalid
+    );
+}
+
+#[test]
+fn load_account_rejects_malformed_data() {
+    let program_id = Pubkey::new_unique();
+    let mut data = pool_bytes();
+    // 2 is not a known account type
+    data[0] = 2;
+
+    assert_eq!(
+        load_pool(&mut data, &program_id, &program_id, AccountType::Pool).unwrap_err(),
+        StakingError::InvalidAccountData.into()
+    );
+}
+
+#[test]
+fn load_account_checks_account_type() {
+    let program_id = Pubkey::new_unique();
+
+    let mut blank = vec![0; PoolStorageAccount::LEN];
+    assert_eq!(
+        load_pool(
+            &mut blank,
+            &program_id,
+            &program_id,
+            AccountType::Uninitialized
+        )
+        .unwrap()
+        .account_type,
+        AccountType::Uninitialized
+    );
+    assert_eq!(
+        load_pool(&mut blank, &program_id, &program_id, AccountType::Pool).unwrap_err(),
+        ProgramError::UninitializedAccount
+    );
+
+    let mut pool = pool_bytes();
+    assert_eq!(
+        load_pool(
+            &mut pool,
+            &program_id,
+            &program_id,
+            AccountType::Uninitialized
+        )
+        .unwrap_err(),
+        StakingError::AlreadyInitialized.into()
+    );
+}
+
+#[test]
+fn account_type_reads_leading_byte() {
+    assert_eq!(AccountType::read(&pool_bytes()), Ok(AccountType::Pool));
+    assert_eq!(
+        AccountType::read(&[0; PoolStorageAccount::LEN]),
+        Ok(AccountType::Uninitialized)
+    );
+    assert_eq!(
+        AccountType::read(&[]),
+        Err(StakingError::InvalidAccountData)
+    );
+    assert_eq!(
+        AccountType::read(&[4]),
+        Err(StakingError::InvalidAccountData)
+    );
+}
+
+#[test]
+fn require_role_accepts_only_the_role_holder() {
+    let (admin, operator, emergency) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let pool = PoolStorageAccount {
+        pool_authority: admin,
+        operator,
+        emergency,
+        ..Default::default()
+    };
+    let holders = [
+        (Role::Admin, admin),
+        (Role::Operator, operator),
+        (Role::Emergency, emergency),
+    ];
+
+    for (role, _) in holders {
+        for (other_role, key) in holders {
+            let owner = Pubkey::new_unique();
+            let (mut lamports, mut data) = (1, vec![]);
+            let account = AccountInfo::new(
+                &key,
+                true,
+                false,
+                &mut lamports,
+                &mut data,
+                &owner,
+                false,
+                0,
+            );
+
+            let expected = if role == other_role {
+                Ok(())
+            } else {
+                Err(StakingError::Unauthorized.into())
+            };
+            assert_eq!(require_role(&pool, &account, role), expected);
+        }
+    }
+}
+
+#[test]
+fn require_active_rejects_shut_down_pool() {
+    let mut pool = PoolStorageAccount::default();
+    assert_eq!(require_active(&pool), Ok(()));
+
+    pool.is_shutdown = true;
+    assert_eq!(
+        require_active(&pool),
+        Err(StakingError::PoolShutdown.into())
+    );
+}
@@ -0,0 +1,259 @@
+//! Golden vectors pinning the Borsh wire format.
+//!
+//! Borsh encodes integers little-endian and enums as a one-byte variant index
+//! followed by the variant's fields. Clients in other languages must produce
+//! exactly these bytes, so any change here is a breaking wire-format change.
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+use stakingdapp::{
+    instruction::Instruction,
+    state::{AccountType, Checkpoint, PoolConfig, PoolStorageAccount, ProgramConfig, Role},
+};
+
+const INITIALIZE: [u8; 9] = [0, 42, 0, 0, 0, 0, 0, 0, 0];
+const CREATE_USER: [u8; 1] = [1];
+const STAKE: [u8; 9] = [2, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01];
+const UNSTAKE: [u8; 9] = [3, 255, 255, 255, 255, 255, 255, 255, 255];
+const CLAIM: [u8; 1] = [4];
+const RECORD_CHECKPOINT: [u8; 1] = [5];
+const LOG_CONFIG: [u8; 1] = [6];
+const CLOSE_POOL: [u8; 1] = [7];
+#[rustfmt::skip]
+const PROPOSE_AUTHORITY: [u8; 33] = [
+    8,
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+];
+const ACCEPT_AUTHORITY: [u8; 1] = [9];
+const CANCEL_AUTHORITY: [u8; 1] = [10];
+#[rustfmt::skip]
+const SET_ROLE: [u8; 34] = [
+    11,
+    // role = Operator
+    1,
+    // holder
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+];
+const QUERY_CHECKPOINT: [u8; 9] = [12, 5, 0, 0, 0, 0, 0, 0, 0];
+const EMERGENCY_SHUTDOWN: [u8; 1] = [13];
+const REFUND_UNINITIALIZED: [u8; 1] = [14];
+
+const UNINITIALIZED: [u8; 1] = [0];
+const POOL_TYPE: [u8; 1] = [1];
+const USER_TYPE: [u8; 1] = [2];
+const REGISTRY_TYPE: [u8; 1] = [3];
+
+const CHECKPOINT: [u8; 16] = [5, 0, 0, 0, 0, 0, 0, 0, 232, 3, 0, 0, 0, 0, 0, 0];
+
+#[rustfmt::skip]
+const POOL: [u8; PoolStorageAccount::LEN] = [
+    // account_type = Pool
+    1,
+    // pool_authority
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    // total_staked = 1000
+    232, 3, 0, 0, 0, 0, 0, 0,
+    // user_count = 3
+    3, 0, 0, 0, 0, 0, 0, 0,
+    // rewards_per_token = 42
+    42, 0, 0, 0, 0, 0, 0, 0,
+    // checkpoints[0] = { slot: 5, total_staked: 1000 }
+    5, 0, 0, 0, 0, 0, 0, 0, 232, 3, 0, 0, 0, 0, 0, 0,
+    // checkpoints[1..16] = zeroed
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    // checkpoint_count = 1
+    1, 0, 0, 0, 0, 0, 0, 0,
+    // pending_authority
+    33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+    49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64,
+    // operator
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96,
+    // emergency
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+    113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128,
+    // is_shutdown = true
+    1,
+];
+
+#[rustfmt::skip]
+const POOL_CONFIG: [u8; PoolConfig::LEN] = [
+    // pool_authority
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+    17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    // rewards_per_token = 42
+    42, 0, 0, 0, 0, 0, 0, 0,
+    // operator
+    65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96,
+    // emergency
+    97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+    113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128,
+];
+
+#[rustfmt::skip]
+const PROGRAM_CONFIG: [u8; ProgramConfig::LEN] = [
+    // pool_account_len = 418
+    162, 1, 0, 0, 0, 0, 0, 0,
+    // max_checkpoints = 16
+    16, 0, 0, 0, 0, 0, 0, 0,
+    // bps_denominator = 10_000
+    16, 39, 0, 0, 0, 0, 0, 0,
+    // max_reward_rate = 1_000_000_000
+    0, 202, 154, 59, 0, 0, 0, 0,
+];
+
+fn assert_instruction(instruction: Instruction, expected: &[u8]) {
+    let bytes = borsh::to_vec(&instruction).unwrap();
+    assert_eq!(bytes, expected);
+
+    // decoding the golden bytes must yield the same instruction
+    let decoded = Instruction::try_from_slice(expected).unwrap();
+    assert_eq!(borsh::to_vec(&decoded).unwrap(), expected);
+}
+
+fn golden_pool() -> PoolStorageAccount {
+    let mut pool = PoolStorageAccount {
+        pool_authority: Pubkey::new_from_array(core::array::from_fn(|i| i as u8 + 1)),
+        total_staked: 1000,
+        user_count: 3,
+        rewards_per_token: 42,
+        account_type: AccountType::Pool,
+        pending_authority: Pubkey::new_from_array(core::array::from_fn(|i| i as u8 + 33)),
+        operator: Pubkey::new_from_array(core::array::from_fn(|i| i as u8 + 65)),
+        emergency: Pubkey::new_from_array(core::array::from_fn(|i| i as u8 + 97)),
+        is_shutdown: true,
+        ..Default::default()
+    };
+    pool.push_checkpoint(5).unwrap();
+    pool
+}
+
+#[test]
+fn instruction_wire_format() {
+    assert_instruction(
+        Instruction::Initialize {
+            rewards_per_token: 42,
+        },
+        &INITIALIZE,
+    );
+    assert_instruction(Instruction::CreateUser {}, &CREATE_USER);
+    assert_instruction(
+        Instruction::Stake {
+            amount: 0x0102_0304_0506_0708,
+        },
+        &STAKE,
+    );
+    assert_instruction(Instruction::Unstake { amount: u64::MAX }, &UNSTAKE);
+    assert_instruction(Instruction::Claim {}, &CLAIM);
+    assert_instruction(Instruction::RecordCheckpoint {}, &RECORD_CHECKPOINT);
+    assert_instruction(Instruction::LogConfig {}, &LOG_CONFIG);
+    assert_instruction(Instruction::ClosePool {}, &CLOSE_POOL);
+    assert_instruction(
+        Instruction::ProposeAuthority {
+            new: Pubkey::new_from_array(core::array::from_fn(|i| i as u8 + 1)),
+        },
+        &PROPOSE_AUTHORITY,
+    );
+    assert_instruction(Instruction::AcceptAuthority {}, &ACCEPT_AUTHORITY);
+    assert_instruction(Instruction::CancelAuthority {}, &CANCEL_AUTHORITY);
+    assert_instruction(
+        Instruction::SetRole {
+            role: Role::Operator,
+            holder: Pubkey::new_from_array(core::array::from_fn(|i| i as u8 + 1)),
+        },
+        &SET_ROLE,
+    );
+    assert_instruction(Instruction::QueryCheckpoint { slot: 5 }, &QUERY_CHECKPOINT);
+    assert_instruction(Instruction::EmergencyShutdown {}, &EMERGENCY_SHUTDOWN);
+    assert_instruction(Instruction::RefundUninitialized {}, &REFUND_UNINITIALIZED);
+}
+
+#[test]
+fn account_type_wire_format() {
+    assert_eq!(
+        borsh::to_vec(&AccountType::Uninitialized).unwrap(),
+        UNINITIALIZED
+    );
+    assert_eq!(borsh::to_vec(&AccountType::Pool).unwrap(), POOL_TYPE);
+    assert_eq!(borsh::to_vec(&AccountType::User).unwrap(), USER_TYPE);
+    assert_eq!(
+        borsh::to_vec(&AccountType::Registry).unwrap(),
+        REGISTRY_TYPE
+    );
+    assert_eq!(AccountType::read(&POOL), Ok(AccountType::Pool));
+}
+
+#[test]
+fn checkpoint_wire_format() {
+    let checkpoint = Checkpoint {
+        slot: 5,
+        total_staked: 1000,
+    };
+    assert_eq!(borsh::to_vec(&checkpoint).unwrap(), CHECKPOINT);
+    assert_eq!(Checkpoint::try_from_slice(&CHECKPOINT).unwrap(), checkpoint);
+}
+
+#[test]
+fn pool_storage_account_wire_format() {
+    assert_eq!(borsh::to_vec(&golden_pool()).unwrap(), POOL);
+
+    let decoded = PoolStorageAccount::try_from_slice(&POOL).unwrap();
+    assert_eq!(borsh::to_vec(&decoded).unwrap(), POOL);
+    assert_eq!(decoded.pool_authority, golden_pool().pool_authority);
+    assert_eq!(decoded.total_staked, 1000);
+    assert_eq!(decoded.user_count, 3);
+    assert_eq!(decoded.rewards_per_token, 42);
+    assert_eq!(decoded.account_type, AccountType::Pool);
+    assert_eq!(decoded.pending_authority, golden_pool().pending_authority);
+    assert_eq!(decoded.operator, golden_pool().operator);
+    assert_eq!(decoded.emergency, golden_pool().emergency);
+    assert!(decoded.is_shutdown);
+    assert_eq!(
+        decoded.recent_checkpoints(),
+        golden_pool().recent_checkpoints()
+    );
+}
+
+#[test]
+fn pool_config_wire_format() {
+    assert_eq!(golden_pool().config_blob(), POOL_CONFIG);
+
+    let decoded = PoolConfig::try_from_slice(&POOL_CONFIG).unwrap();
+    assert_eq!(decoded, golden_pool().config());
+    assert_eq!(decoded.pool_authority, golden_pool().pool_authority);
+    assert_eq!(decoded.rewards_per_token, 42);
+    assert_eq!(decoded.operator, golden_pool().operator);
+    assert_eq!(decoded.emergency, golden_pool().emergency);
+}
+
+#[test]
+fn program_config_wire_format() {
+    let config = ProgramConfig {
+        pool_account_len: 418,
+        max_checkpoints: 16,
+        bps_denominator: 10_000,
+        max_reward_rate: 1_000_000_000,
+    };
+    assert_eq!(borsh::to_vec(&config).unwrap(), PROGRAM_CONFIG);
+    assert_eq!(
+        ProgramConfig::try_from_slice(&PROGRAM_CONFIG).unwrap(),
+        config
+    );
+}